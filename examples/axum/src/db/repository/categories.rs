@@ -68,13 +68,15 @@ impl<'p> CategoriesRepositoryMethods for CategoriesRepository<'p> {
         match search_by.get_name_like() {
             Some(name_like) => {
                 debug!("filtering by name_like: {}", name_like);
-                query.push(format!("AND LOWER(name) ILIKE '%{name_like}%'"));
+                query
+                    .push(" AND LOWER(name) ILIKE ")
+                    .push_bind(format!("%{name_like}%"));
             }
             None => debug!("No name_like provided"),
         }
 
         let categories: PaginatedCategories = query
-            .paginate(self.get_pool(), search_by.get_page(), search_by.get_size())
+            .paginate_bound(self.get_pool(), search_by.get_page(), search_by.get_size())
             .await?;
 
         Ok(categories)
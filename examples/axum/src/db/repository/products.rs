@@ -93,7 +93,9 @@ impl<'p> ProductsRepositoryMethods for ProductsRepository<'p> {
         match search_by.get_name_like() {
             Some(name_like) => {
                 debug!("filtering by name_like: {}", name_like);
-                query.push(format!("AND LOWER(p.name) ILIKE '%{name_like}%'"));
+                query
+                    .push(" AND LOWER(p.name) ILIKE ")
+                    .push_bind(format!("%{name_like}%"));
             }
             None => debug!("No name_like provided"),
         }
@@ -101,9 +103,9 @@ impl<'p> ProductsRepositoryMethods for ProductsRepository<'p> {
         match search_by.get_description_like() {
             Some(description_like) => {
                 debug!("filtering by description_like: {}", description_like);
-                query.push(format!(
-                    "AND LOWER(p.description) ILIKE '%{description_like}%'"
-                ));
+                query
+                    .push(" AND LOWER(p.description) ILIKE ")
+                    .push_bind(format!("%{description_like}%"));
             }
             None => debug!("No description_like provided"),
         }
@@ -111,7 +113,7 @@ impl<'p> ProductsRepositoryMethods for ProductsRepository<'p> {
         match search_by.get_category_id() {
             Some(category_id) => {
                 debug!("filtering by category_id: {}", category_id);
-                query.push(format!("AND category_id = '{}'", category_id));
+                query.push(" AND category_id = ").push_bind(category_id);
             }
             None => debug!("No category_id provided"),
         }
@@ -119,13 +121,15 @@ impl<'p> ProductsRepositoryMethods for ProductsRepository<'p> {
         match search_by.get_category_name_like() {
             Some(category_name_like) => {
                 debug!("filtering by category_name_like: {}", category_name_like);
-                query.push(format!("AND LOWER(c.name) ILIKE '%{category_name_like}%'"));
+                query
+                    .push(" AND LOWER(c.name) ILIKE ")
+                    .push_bind(format!("%{category_name_like}%"));
             }
             None => debug!("No category_name_like provided"),
         }
 
         let products_base: PaginatedProductsBase = query
-            .paginate(self.get_pool(), search_by.get_page(), search_by.get_size())
+            .paginate_bound(self.get_pool(), search_by.get_page(), search_by.get_size())
             .await?;
 
         let products: PaginatedProducts = Page::new(
@@ -67,10 +67,14 @@ impl From<PaginationError> for HttpException {
         match error.get_error_kind() {
             ErrorKind::FieldValueError(details) => Self::internal_server_error(details),
 
+            kind @ ErrorKind::OutOfRangePage { .. } => Self::not_found(&kind.description()),
+
             ErrorKind::SQLxError(error) => match error {
                 SqlxError::RowNotFound => Self::not_found(&error.to_string()),
                 _ => Self::internal_server_error(&error.to_string()),
             },
+
+            kind => Self::internal_server_error(&kind.description()),
         }
     }
 }
@@ -19,10 +19,23 @@
 //! ```
 //!
 //! ## CRATE FEATURES
+//! - `std`: Enabled by default. Link the Rust standard library and add the conversion of [`PaginationError`] into `std::io::Error`. Without it the crate is `no_std` and only depends on `alloc`, so [`Page`], [`Book`] and [`paginate_records`] can be used in embedded or WebAssembly targets with `default-features = false`. Every other feature depends on the `std` feature.
 //! - `serde`: Add [Serialize](https://docs.rs/serde/1.0.203/serde/trait.Serialize.html) and [Deserialize](https://docs.rs/serde/1.0.203/serde/trait.Deserialize.html) support for [`Page`] and [`Book`] based on [serde](https://crates.io/crates/serde/1.0.203). This feature is useful for implementing pagination models as a request or response body in REST APIs, among other implementations.
+//! - `serde-camel-case`: Rename the ***previous_page*** and ***next_page*** fields of [`Page`] to `previousPage` and `nextPage` on serialization and in the generated schemas. The snake_case names are still accepted on deserialization. This feature is useful for front ends consuming camelCase JSON. This feature depends on the `serde` feature.
+//! - `serde-skip-null-links`: Omit the ***previous_page*** and ***next_page*** fields of [`Page`] on serialization when they are `None`, instead of writing `null`. Missing links are still deserialized as `None`. This feature is useful for reducing the size of JSON payloads. The `to_bytes` method of the `bincode` feature still encodes every field. This feature depends on the `serde` feature.
 //!  - `utoipa`: Add [ToSchema](https://docs.rs/utoipa/4.2.3/utoipa/trait.ToSchema.html) support for [`Page`] and  [`Book`] based on [utoipa](https://crates.io/crates/utoipa/4.2.3). This feature is useful for generating OpenAPI schemas for pagination models. This feature depends on the `serde` feature and therefore you only need to implement `utoipa` to get both.
+//! - `utoipa-5`: Add [PartialSchema](https://docs.rs/utoipa/5.3.1/utoipa/trait.PartialSchema.html) and [ToSchema](https://docs.rs/utoipa/5.3.1/utoipa/trait.ToSchema.html) support for [`Page`] and [`Book`] based on [utoipa](https://crates.io/crates/utoipa/5.3.1) 5.x. This feature is useful for generating OpenAPI schemas with the current utoipa API, and can be enabled together with `utoipa`. This feature depends on the `serde` feature.
 //! - `pg-sqlx`: Add support for pagination with [SQLx](https://docs.rs/sqlx/0.7.4/sqlx/) for PostgreSQL database.
+//! - `pg-estimated-count`: Add a `paginate_estimated` method for PostgreSQL that derives the total number of records from the rows estimated by the query planner instead of an exact `count(*)`. This feature is useful for paginating huge tables, where the returned totals are approximate. This feature depends on the `pg-sqlx` feature.
 //! - `mysql-sqlx`: Add support for pagination with [SQLx](https://docs.rs/sqlx/0.7.4/sqlx/)  for MySQL database.
+//! - `validator`: Add [Validate](https://docs.rs/validator/0.18.1/validator/trait.Validate.html) support for [`Page`] based on [validator](https://crates.io/crates/validator/0.18.1). This feature is useful for validating pagination models nested in request bodies with `#[validate(nested)]`.
+//! - `bincode`: Add `to_bytes` and `from_bytes` methods to [`Page`] based on [bincode](https://crates.io/crates/bincode/1.3.3), verifying the fields on decode. This feature is useful for caching pagination models in a binary format. This feature depends on the `serde` feature.
+//! - `deepsize`: Add [DeepSizeOf](https://docs.rs/deepsize/0.2.0/deepsize/trait.DeepSizeOf.html) support and a `heap_size` method for [`Page`] based on [deepsize](https://crates.io/crates/deepsize/0.2.0). This feature is useful for estimating the memory used by cached pagination models.
+//! - `rand`: Add a `sample` method to [`Page`] based on [rand](https://crates.io/crates/rand/0.8.5). This feature is useful for taking random previews of the items of a page.
+//! - `reqwest`: Add `fetch_page` and `walk_pages` functions to consume paginated HTTP APIs based on [reqwest](https://crates.io/crates/reqwest/0.12.5). This feature is useful for fetching a [`Page`] from a REST API and walking through all its pages. This feature depends on the `serde` feature. It builds reqwest without default features, so no TLS backend is included: enable a TLS feature of reqwest, such as `rustls-tls` or `native-tls`, in your own dependencies to fetch `https://` URLs.
+//! - `schemars`: Add [JsonSchema](https://docs.rs/schemars/0.8.21/schemars/trait.JsonSchema.html) support for [`Page`] and [`Book`] based on [schemars](https://crates.io/crates/schemars/0.8.21). This feature is useful for generating JSON Schema documents of pagination models. This feature depends on the `serde` feature.
+//! - `stream`: Add a `paginate_stream` function to paginate the records of an asynchronous [Stream](https://docs.rs/futures-util/0.3.30/futures_util/stream/trait.Stream.html) into a [`Page`] based on [futures-util](https://crates.io/crates/futures-util/0.3.30). This feature is useful for paginating records from asynchronous sources without buffering them into a collection first.
+//! - `rayon`: Add a `par_bind_records` function to bind records into a [`Book`] building its pages in parallel based on [rayon](https://crates.io/crates/rayon/1.10.0). This feature is useful for binding large collections of records that are expensive to clone.
 //!
 //! ## BASIC OPERATION
 //!
@@ -64,6 +77,8 @@
 //!
 //! On feature `serde` enabled, you can serialize and deserialize a [`Page`] as follows:
 //! ```rust,no_run
+//! # #[cfg(feature = "serde")]
+//! # fn main() {
 //!     use page_hunter::*;
 //!
 //!     let items: Vec<u32> = vec![1, 2];
@@ -87,6 +102,9 @@
 //!     let deserialized_page: Page<u32> = serde_json::from_str(&serialized_page).unwrap_or_else(|error| {
 //!         panic!("Error deserializing page model: {:?}", error);
 //!     });
+//! # }
+//! # #[cfg(not(feature = "serde"))]
+//! # fn main() {}
 //! ```
 //!
 //! When you create a new [`Page`] instance from the constructor or deserialization, the following rules are validated for the fields on the page:
@@ -125,6 +143,8 @@
 //!
 //! On feature `serde` enabled, you can serialize and deserialize a [`Book`] as follows:
 //! ```rust,no_run
+//! # #[cfg(feature = "serde")]
+//! # fn main() {
 //!     use page_hunter::*;
 //!
 //!     let sheets: Vec<Page<u32>> = vec![
@@ -141,39 +161,49 @@
 //!     let deserialized_book: Book<u32> = serde_json::from_str(&serialized_book).unwrap_or_else(|error| {
 //!         panic!("Error deserializing book model: {:?}", error);
 //!     });
+//! # }
+//! # #[cfg(not(feature = "serde"))]
+//! # fn main() {}
 //! ```
 //!
-//!	#### Generate OpenAPI schemas:
+//! #### Generate OpenAPI schemas:
 //! On feature `utoipa` enabled, you can generate OpenAPI schemas for [`Page`] and [`Book`] models as follows:
 //!
 //! ```rust,no_run
-//! 	use page_hunter::*;
-//! 	use utoipa::{OpenApi, ToSchema};
-//! 	use serde::{Deserialize, Serialize};
-//!
-//! 	#[derive(Clone, ToSchema)]
-//! 	pub struct Person {
-//! 		id: u16,
-//! 		name: String,
-//! 		last_name: String,
-//! 		still_alive: bool,
-//! 	}
-//!
-//! 	pub type PeoplePage = Page<Person>;
-//! 	pub type PeopleBook = Book<Person>;
-//!
-//! 	#[derive(OpenApi)]
-//!  	#[openapi(
-//!     	components(schemas(PeoplePage, PeopleBook))
-//!  	)]
-//!  	pub struct ApiDoc;
-//!	```
-//!
-//!	Take a look at the [examples](https://github.com/JMTamayo/page-hunter/tree/main/examples)  folder where you can find practical uses in REST API implementations with some web frameworks.
+//! # #[cfg(feature = "utoipa")]
+//! # fn main() {
+//!     use page_hunter::*;
+//!     use utoipa::{OpenApi, ToSchema};
+//!     use serde::{Deserialize, Serialize};
+//!
+//!     #[derive(Clone, ToSchema)]
+//!     pub struct Person {
+//!         id: u16,
+//!         name: String,
+//!         last_name: String,
+//!         still_alive: bool,
+//!     }
+//!
+//!     pub type PeoplePage = Page<Person>;
+//!     pub type PeopleBook = Book<Person>;
+//!
+//!     #[derive(OpenApi)]
+//!     #[openapi(
+//!         components(schemas(PeoplePage, PeopleBook))
+//!     )]
+//!     pub struct ApiDoc;
+//! # }
+//! # #[cfg(not(feature = "utoipa"))]
+//! # fn main() {}
+//! ```
+//!
+//! Take a look at the [examples](https://github.com/JMTamayo/page-hunter/tree/main/examples)  folder where you can find practical uses in REST API implementations with some web frameworks.
 //!
 //! #### Paginate records from a PostgreSQL database with SQLx:
 //! To paginate records from a PostgreSQL database:
 //! ```rust,no_run
+//! # #[cfg(feature = "pg-sqlx")]
+//! # mod example {
 //!     use page_hunter::*;
 //!     use sqlx::postgres::{PgPool, Postgres};
 //!     use sqlx::{FromRow, QueryBuilder};
@@ -202,10 +232,14 @@
 //!                 panic!("Error paginating records: {:?}", error);
 //!             });
 //!     }
+//! # }
+//! # fn main() {}
 //! ```
 //!
 //! To paginate records from a MySQL database:
 //! ```rust,no_run
+//! # #[cfg(feature = "mysql-sqlx")]
+//! # mod example {
 //!     use page_hunter::*;
 //!     use sqlx::mysql::{MySqlPool, MySql};
 //!     use sqlx::{FromRow, QueryBuilder};
@@ -234,6 +268,8 @@
 //!                 panic!("Error paginating records: {:?}", error);
 //!             });
 //!     }
+//! # }
+//! # fn main() {}
 //! ```
 //!
 //! ## CONTRIBUTIONS
@@ -243,6 +279,10 @@
 //! - **Feature Requests**: If you have an idea for a new feature or an enhancement to an existing one, please create an issue describing your idea.
 //! - **Pull Requests**: If you've fixed a bug or implemented a new feature, we'd love to see your work! Please submit a pull request. Make sure your code follows the existing style and all tests pass.
 
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
 mod page_hunter;
 
 pub use page_hunter::errors::*;
@@ -251,3 +291,9 @@ pub use page_hunter::records_pagination::*;
 
 #[cfg(any(feature = "pg-sqlx", feature = "mysql-sqlx"))]
 pub use page_hunter::sqlx_pagination::*;
+
+#[cfg(feature = "reqwest")]
+pub use page_hunter::reqwest_pagination::*;
+
+#[cfg(feature = "stream")]
+pub use page_hunter::stream_pagination::*;
@@ -1,4 +1,11 @@
-use std::fmt::{Debug, Display, Formatter, Result};
+use alloc::{borrow::ToOwned, format, string::String};
+use core::fmt::{Debug, Display, Formatter, Result};
+
+#[cfg(feature = "std")]
+use alloc::string::ToString;
+
+#[cfg(feature = "std")]
+use std::io::{Error as IoError, ErrorKind as IoErrorKind};
 
 #[allow(unused_imports)]
 use super::models::Page;
@@ -6,27 +13,91 @@ use super::models::Page;
 #[cfg(any(feature = "pg-sqlx", feature = "mysql-sqlx"))]
 use sqlx::Error as SqlxError;
 
+#[cfg(feature = "reqwest")]
+use reqwest::Error as ReqwestError;
+
 /// Provides a way to categorize the pagination error.
+///
+/// [`ErrorKind`] is marked as `#[non_exhaustive]`, so new variants can be added without breaking downstream code. Any `match` on [`ErrorKind`] outside this crate must include a wildcard arm (`_ => ...`), where [`ErrorKind::description`] can be used to render the kind.
+#[non_exhaustive]
 pub enum ErrorKind {
     /// Raised when a value in a field on the [`Page`] is invalid based on the pagination logic.
     FieldValueError(String),
 
+    /// Raised when a requested page index is out of the range of pages available for the total number of records.
+    OutOfRangePage {
+        /// The requested page index.
+        requested: usize,
+
+        /// The total number of pages.
+        total_pages: usize,
+    },
+
     /// Raised during a database operation using the [`sqlx`]. Only available when the `pg-sqlx` or `mysql-sqlx` features are enabled.
     #[cfg(any(feature = "pg-sqlx", feature = "mysql-sqlx"))]
     SQLxError(SqlxError),
+
+    /// Raised when a row fetched using the [`sqlx`] cannot be mapped into the record type with [`FromRow`](sqlx::FromRow), such as a field missing in the query columns. This is usually a bug in the query or the record type rather than a database failure. Only available when the `pg-sqlx` or `mysql-sqlx` features are enabled.
+    #[cfg(any(feature = "pg-sqlx", feature = "mysql-sqlx"))]
+    FromRowError(String),
+
+    /// Raised during a HTTP request using the [`reqwest`] client. Only available when the `reqwest` feature is enabled.
+    #[cfg(feature = "reqwest")]
+    ReqwestError(ReqwestError),
 }
 
 impl ErrorKind {
+    /// Get the description of the [`ErrorKind`] without its category prefix. This is a stable way to render any kind without an exhaustive `match`.
+    pub fn description(&self) -> String {
+        match self {
+            ErrorKind::FieldValueError(detail) => detail.to_owned(),
+
+            ErrorKind::OutOfRangePage {
+                requested,
+                total_pages,
+            } => format!(
+                "Page index '{}' exceeds total pages '{}'",
+                requested, total_pages
+            ),
+
+            #[cfg(any(feature = "pg-sqlx", feature = "mysql-sqlx"))]
+            ErrorKind::SQLxError(detail) => detail.to_string(),
+
+            #[cfg(any(feature = "pg-sqlx", feature = "mysql-sqlx"))]
+            ErrorKind::FromRowError(detail) => detail.to_owned(),
+
+            #[cfg(feature = "reqwest")]
+            ErrorKind::ReqwestError(detail) => detail.to_string(),
+        }
+    }
+
     /// Check if the [`ErrorKind`] is a [`ErrorKind::FieldValueError`].
     pub fn is_field_value_error(&self) -> bool {
         matches!(self, ErrorKind::FieldValueError(_))
     }
 
+    /// Check if the [`ErrorKind`] is a [`ErrorKind::OutOfRangePage`].
+    pub fn is_out_of_range_page(&self) -> bool {
+        matches!(self, ErrorKind::OutOfRangePage { .. })
+    }
+
     /// Check if the [`ErrorKind`] is a [`ErrorKind::SQLxError`]. Only available when the `pg-sqlx` or `mysql-sqlx` features are enabled.
     #[cfg(any(feature = "pg-sqlx", feature = "mysql-sqlx"))]
     pub fn is_sqlx_error(&self) -> bool {
         matches!(self, ErrorKind::SQLxError(_))
     }
+
+    /// Check if the [`ErrorKind`] is a [`ErrorKind::FromRowError`]. Only available when the `pg-sqlx` or `mysql-sqlx` features are enabled.
+    #[cfg(any(feature = "pg-sqlx", feature = "mysql-sqlx"))]
+    pub fn is_from_row_error(&self) -> bool {
+        matches!(self, ErrorKind::FromRowError(_))
+    }
+
+    /// Check if the [`ErrorKind`] is a [`ErrorKind::ReqwestError`]. Only available when the `reqwest` feature is enabled.
+    #[cfg(feature = "reqwest")]
+    pub fn is_reqwest_error(&self) -> bool {
+        matches!(self, ErrorKind::ReqwestError(_))
+    }
 }
 
 /// Implementation of [`Display`] for [`ErrorKind`].
@@ -35,8 +106,18 @@ impl Display for ErrorKind {
         match self {
             ErrorKind::FieldValueError(detail) => write!(f, "FIELD VALUE ERROR- {}", detail),
 
+            ErrorKind::OutOfRangePage { .. } => {
+                write!(f, "OUT OF RANGE PAGE ERROR- {}", self.description())
+            }
+
             #[cfg(any(feature = "pg-sqlx", feature = "mysql-sqlx"))]
             ErrorKind::SQLxError(detail) => write!(f, "SQLX ERROR- {}", detail),
+
+            #[cfg(any(feature = "pg-sqlx", feature = "mysql-sqlx"))]
+            ErrorKind::FromRowError(detail) => write!(f, "FROM ROW ERROR- {}", detail),
+
+            #[cfg(feature = "reqwest")]
+            ErrorKind::ReqwestError(detail) => write!(f, "REQWEST ERROR- {}", detail),
         }
     }
 }
@@ -47,8 +128,23 @@ impl Debug for ErrorKind {
         match self {
             ErrorKind::FieldValueError(detail) => write!(f, "FieldValueError({:?})", detail),
 
+            ErrorKind::OutOfRangePage {
+                requested,
+                total_pages,
+            } => write!(
+                f,
+                "OutOfRangePage {{ requested: {:?}, total_pages: {:?} }}",
+                requested, total_pages
+            ),
+
             #[cfg(any(feature = "pg-sqlx", feature = "mysql-sqlx"))]
             ErrorKind::SQLxError(detail) => write!(f, "SqlxError({:?})", detail),
+
+            #[cfg(any(feature = "pg-sqlx", feature = "mysql-sqlx"))]
+            ErrorKind::FromRowError(detail) => write!(f, "FromRowError({:?})", detail),
+
+            #[cfg(feature = "reqwest")]
+            ErrorKind::ReqwestError(detail) => write!(f, "ReqwestError({:?})", detail),
         }
     }
 }
@@ -56,6 +152,7 @@ impl Debug for ErrorKind {
 /// Error type used throughout the library for error handling.
 pub struct PaginationError {
     kind: ErrorKind,
+    context: Option<String>,
 }
 
 impl PaginationError {
@@ -63,26 +160,69 @@ impl PaginationError {
     pub fn get_error_kind(&self) -> &ErrorKind {
         &self.kind
     }
+
+    /// Get the context of the error, if any. For example, the SQLx pagination tags which query failed and the requested page and size.
+    pub fn get_context(&self) -> Option<&str> {
+        self.context.as_deref()
+    }
+
+    /// Get the underlying [`sqlx::Error`] if the [`ErrorKind`] is a [`ErrorKind::SQLxError`], otherwise [`None`]. This is useful to inspect the database error, e.g. to check for a unique violation. Only available when the `pg-sqlx` or `mysql-sqlx` features are enabled.
+    #[cfg(any(feature = "pg-sqlx", feature = "mysql-sqlx"))]
+    pub fn as_sqlx_error(&self) -> Option<&SqlxError> {
+        match self.get_error_kind() {
+            ErrorKind::SQLxError(error) => Some(error),
+            _ => None,
+        }
+    }
+
+    /// Attach a context to the error, replacing any previous context. The context is appended to the [`Display`] message.
+    ///
+    /// ### Arguments:
+    /// - **context**: A description of the operation that failed.
+    ///
+    /// ### Returns:
+    /// The [`PaginationError`] with the given context.
+    pub fn with_context<C>(mut self, context: C) -> Self
+    where
+        C: Into<String>,
+    {
+        self.context = Some(context.into());
+        self
+    }
 }
 
 /// Implementation of [`Display`] for [`PaginationError`].
 impl Display for PaginationError {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
-        write!(f, "{}", self.get_error_kind())
+        match self.get_context() {
+            Some(context) => write!(f, "{} ({})", self.get_error_kind(), context),
+            None => write!(f, "{}", self.get_error_kind()),
+        }
     }
 }
 
 /// Implementation of [`Debug`] for [`PaginationError`].
 impl Debug for PaginationError {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
-        write!(f, "PaginationError {{ kind: {:?} }}", self.get_error_kind())
+        match self.get_context() {
+            Some(context) => write!(
+                f,
+                "PaginationError {{ kind: {:?}, context: {:?} }}",
+                self.get_error_kind(),
+                context
+            ),
+            None => write!(f, "PaginationError {{ kind: {:?} }}", self.get_error_kind()),
+        }
     }
 }
 
 /// Implementation of [`From`]<[`ErrorKind`]> for [`PaginationError`].
 impl From<ErrorKind> for PaginationError {
     fn from(value: ErrorKind) -> Self {
-        Self { kind: value }
+        Self {
+            kind: value,
+            context: None,
+        }
     }
 }
 
@@ -92,6 +232,43 @@ impl From<SqlxError> for PaginationError {
     fn from(value: sqlx::Error) -> Self {
         Self {
             kind: ErrorKind::SQLxError(value),
+            context: None,
         }
     }
 }
+
+/// Implementation of [`From`]<[`reqwest::Error`]> for [`PaginationError`]. Only available when the `reqwest` feature is enabled.
+#[cfg(feature = "reqwest")]
+impl From<ReqwestError> for PaginationError {
+    fn from(value: ReqwestError) -> Self {
+        Self {
+            kind: ErrorKind::ReqwestError(value),
+            context: None,
+        }
+    }
+}
+
+/// Implementation of [`From`]<[`PaginationError`]> for [`std::io::Error`]. Only available when the `std` feature is enabled.
+///
+/// A [`ErrorKind::FieldValueError`] and a [`ErrorKind::FromRowError`] are mapped to [`std::io::ErrorKind::InvalidData`], a [`ErrorKind::OutOfRangePage`] is mapped to [`std::io::ErrorKind::InvalidInput`], while a [`ErrorKind::SQLxError`] and a [`ErrorKind::ReqwestError`] are mapped to [`std::io::ErrorKind::Other`]. The display message of the [`PaginationError`] is preserved.
+#[cfg(feature = "std")]
+impl From<PaginationError> for IoError {
+    fn from(value: PaginationError) -> Self {
+        let kind: IoErrorKind = match value.get_error_kind() {
+            ErrorKind::FieldValueError(_) => IoErrorKind::InvalidData,
+
+            ErrorKind::OutOfRangePage { .. } => IoErrorKind::InvalidInput,
+
+            #[cfg(any(feature = "pg-sqlx", feature = "mysql-sqlx"))]
+            ErrorKind::SQLxError(_) => IoErrorKind::Other,
+
+            #[cfg(any(feature = "pg-sqlx", feature = "mysql-sqlx"))]
+            ErrorKind::FromRowError(_) => IoErrorKind::InvalidData,
+
+            #[cfg(feature = "reqwest")]
+            ErrorKind::ReqwestError(_) => IoErrorKind::Other,
+        };
+
+        IoError::new(kind, value.to_string())
+    }
+}
@@ -1,4 +1,6 @@
 pub mod errors;
 pub mod models;
 pub mod records_pagination;
+pub mod reqwest_pagination;
 pub mod sqlx_pagination;
+pub mod stream_pagination;
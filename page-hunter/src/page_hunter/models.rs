@@ -1,22 +1,81 @@
-use std::fmt::{Debug, Display};
+use alloc::{borrow::ToOwned, format, string::String, vec, vec::Vec};
+use core::cmp::Ordering;
+use core::fmt::{Debug, Display};
 
 use super::errors::{ErrorKind, PaginationError};
+use super::records_pagination::{bind_records, paginate_records};
 
 #[cfg(feature = "serde")]
 use serde::{
-    de::{Deserialize as DeDeserialize, Deserializer as DeDeserializer, Error as DeError},
+    de::{
+        value::{MapAccessDeserializer, SeqAccessDeserializer},
+        Deserialize as DeDeserialize, Deserializer as DeDeserializer, Error as DeError, MapAccess,
+        SeqAccess, Visitor,
+    },
     Deserialize, Serialize, Serializer,
 };
 
+#[cfg(feature = "serde")]
+use core::marker::PhantomData;
+
+#[cfg(feature = "deepsize")]
+use deepsize::{Context as DeepSizeContext, DeepSizeOf};
+
+#[cfg(feature = "rand")]
+use rand::{seq::SliceRandom, Rng};
+
+#[cfg(feature = "validator")]
+use validator::{Validate, ValidationError, ValidationErrors};
+
 #[cfg(feature = "utoipa")]
 use utoipa::{
     openapi::{schema::Schema, ArrayBuilder, KnownFormat, ObjectBuilder, SchemaFormat, SchemaType},
     ToSchema,
 };
 
+#[cfg(feature = "utoipa-5")]
+use utoipa5::{
+    openapi::{
+        schema::{
+            ArrayBuilder as ArrayBuilder5, KnownFormat as KnownFormat5,
+            ObjectBuilder as ObjectBuilder5, Schema as Schema5, SchemaFormat as SchemaFormat5,
+            SchemaType as SchemaType5, Type as Type5,
+        },
+        RefOr as RefOr5,
+    },
+    PartialSchema as PartialSchema5, ToSchema as ToSchema5,
+};
+
+#[cfg(feature = "schemars")]
+use schemars::{
+    gen::SchemaGenerator,
+    schema::{
+        ArrayValidation, InstanceType, Metadata, NumberValidation, ObjectValidation,
+        Schema as SchemarsSchema, SchemaObject, SingleOrVec,
+    },
+    JsonSchema,
+};
+
 /// Result type used throughout the library for result handling.
 pub type PaginationResult<E> = Result<E, PaginationError>;
 
+/// Name of the ***previous_page*** field in the schemas of serialized pages, renamed to `previousPage` if the feature `serde-camel-case` is enabled.
+#[cfg(any(feature = "utoipa", feature = "utoipa-5", feature = "schemars"))]
+const PREVIOUS_PAGE_FIELD: &str = match cfg!(feature = "serde-camel-case") {
+    true => "previousPage",
+    false => "previous_page",
+};
+
+/// Name of the ***next_page*** field in the schemas of serialized pages, renamed to `nextPage` if the feature `serde-camel-case` is enabled.
+#[cfg(any(feature = "utoipa", feature = "utoipa-5", feature = "schemars"))]
+const NEXT_PAGE_FIELD: &str = match cfg!(feature = "serde-camel-case") {
+    true => "nextPage",
+    false => "next_page",
+};
+
+/// Requests of the previous and the next pages returned by [`Page::neighbors`], where each one is an optional tuple of the page index and size.
+pub type PageNeighbors = (Option<(usize, usize)>, Option<(usize, usize)>);
+
 /// Model to represent paginated items.
 ///
 /// #### Fields:
@@ -39,10 +98,36 @@ pub struct Page<E> {
 
 impl<E> Page<E> {
     /// Get ***items***
+    ///
+    /// Prefer [`Page::as_slice`], which borrows the items without exposing the [`Vec`] holding them.
     pub fn get_items(&self) -> &Vec<E> {
         &self.items
     }
 
+    /// Get ***items*** as a slice, which is the preferred way to borrow the items of the current [`Page`], e.g. to pass them to functions accepting `&[E]`.
+    pub fn as_slice(&self) -> &[E] {
+        &self.items
+    }
+
+    /// Get a reference to the item at **index** within the current [`Page`], or [`None`] if **index** is out of bounds.
+    pub fn get(&self, index: usize) -> Option<&E> {
+        self.items.get(index)
+    }
+
+    /// Get ***items*** as a mutable slice, which is the preferred way to edit the items in place without rebuilding the [`Page`], e.g. to redact a field.
+    ///
+    /// The length of ***items*** cannot change through the returned slice, so the invariants of the [`Page`] are kept.
+    pub fn items_mut(&mut self) -> &mut [E] {
+        &mut self.items
+    }
+
+    /// Get a mutable reference to ***items***, which is useful to adjust the items in place without rebuilding the [`Page`].
+    ///
+    /// Changing the length of ***items*** can break the invariants of the [`Page`]. Call [`Page::verify_fields`] afterward if the number of items changes, or prefer [`Page::items_mut`] when it does not.
+    pub fn get_items_mut(&mut self) -> &mut Vec<E> {
+        &mut self.items
+    }
+
     /// Get ***page***
     pub fn get_page(&self) -> usize {
         self.page
@@ -58,6 +143,11 @@ impl<E> Page<E> {
         self.total
     }
 
+    /// Get the number of ***items*** on the current [`Page`], which is less than ***size*** on a last page that is not full.
+    pub fn get_total_on_page(&self) -> usize {
+        self.items.len()
+    }
+
     /// Get ***pages***
     pub fn get_pages(&self) -> usize {
         self.pages
@@ -73,6 +163,36 @@ impl<E> Page<E> {
         self.next_page
     }
 
+    /// Get the numeric fields of the [`Page`] as `(name, value)` pairs, which is useful to attach them to logging spans or metrics without parsing the [`Display`] output.
+    ///
+    /// ### Arguments:
+    /// *No arguments*
+    ///
+    /// ### Returns:
+    /// An array with the ***page***, ***size***, ***total*** and ***pages*** fields, and the ***items*** length, in that order.
+    ///
+    /// ### Example:
+    ///```rust,no_run
+    /// use page_hunter::*;
+    ///
+    /// let page: Page<u32> = Page::new(&vec![1, 2], 0, 2, 5).unwrap_or_else(|error| {
+    ///     panic!("Error creating page model: {:?}", error);
+    /// });
+    ///
+    /// for (name, value) in page.record_fields() {
+    ///     println!("{}: {}", name, value);
+    /// }
+    /// ````
+    pub fn record_fields(&self) -> [(&'static str, usize); 5] {
+        [
+            ("page", self.get_page()),
+            ("size", self.get_size()),
+            ("total", self.get_total()),
+            ("pages", self.get_pages()),
+            ("items", self.get_items().len()),
+        ]
+    }
+
     /// Verify [`Page`] fields.
     ///
     /// ### Arguments:
@@ -83,19 +203,41 @@ impl<E> Page<E> {
     ///
     /// This method is used to check if the fields of a [`Page`] are valid based on the following criteria:
     /// - ***pages*** must be equal to ***total*** divided by ***size*** rounded up. When ***size*** is 0, ***pages*** must be 1.
-    /// - ***page*** must be less than or equal to ***pages*** - 1.
+    /// - ***page*** must be less than or equal to ***pages*** - 1, except for an empty [`Page`] beyond the last page, as returned by [`paginate_records_clamped`](crate::paginate_records_clamped), whose ***previous_page*** must be ***pages*** - 1 and whose ***next_page*** must be [`None`].
     /// - if ***page*** is less than ***pages*** - 1, ***items*** length must be equal to ***size***.
     /// - if ***page*** is equal to ***pages*** - 1, ***total*** must be equal to (***pages*** - 1) * ***size*** + ***items*** length.
     /// - ***previous_page*** must be equal to ***page*** - 1 if ***page*** is greater than 0, otherwise it must be [`None`].
     /// - ***next_page*** must be equal to ***page*** + 1 if ***page*** is less than ***pages*** - 1, otherwise it must be [`None`].
-    fn verify_fields(&self) -> PaginationResult<()> {
+    ///
+    /// An empty [`Page`] beyond the last page can have any ***page*** index, so the methods of [`Page`] do not assume that ***page*** * ***size*** fits in a [`usize`] for it. The methods rebuilding a [`Page`] from its fields, such as [`Page::checked_map`] and [`Page::with_items`], keep it beyond the last page as long as it has no items, while the constructors still reject it.
+    pub fn verify_fields(&self) -> PaginationResult<()> {
+        self.verify_fields_with_links(false)
+    }
+
+    /// Verify [`Page`] fields for a circular page created with [`Page::new_circular`].
+    ///
+    /// ### Arguments:
+    /// *No arguments*
+    ///
+    /// ### Returns:
+    /// A [`PaginationResult`]  with a `()` if successful, otherwise a [`PaginationError`] is returned.
+    ///
+    /// The criteria are the same as [`Page::verify_fields`] except for the wrap-around links when there is more than one page:
+    /// - ***previous_page*** must be equal to ***page*** - 1 if ***page*** is greater than 0, otherwise it must be ***pages*** - 1.
+    /// - ***next_page*** must be equal to ***page*** + 1 if ***page*** is less than ***pages*** - 1, otherwise it must be 0.
+    ///
+    /// When there is a single page, both links must be [`None`]. An empty [`Page`] beyond the last page is rejected, since only the non-circular clamped paginations return it.
+    pub fn verify_fields_circular(&self) -> PaginationResult<()> {
+        self.verify_fields_with_links(true)
+    }
+
+    /// Verify [`Page`] fields, expecting wrap-around ***previous_page*** and ***next_page*** when **circular** is `true`.
+    fn verify_fields_with_links(&self, circular: bool) -> PaginationResult<()> {
         let items_length: usize = self.get_items().len();
 
         // pages must be equal to total divided by size rounded up. When size is 0, pages must be 1.
-        let expected_pages: usize = match self.get_size().eq(&0) {
-            true => 1,
-            false => self.get_total().div_ceil(self.get_size()).max(1),
-        };
+        let expected_pages: usize =
+            Page::<E>::page_count_for_total(self.get_total(), self.get_size());
         if expected_pages.ne(&self.get_pages()) {
             return Err(PaginationError::from(ErrorKind::FieldValueError(format!(
                 "Total pages error: expected '{}', found '{}'",
@@ -104,13 +246,15 @@ impl<E> Page<E> {
             ))));
         }
 
-        // page must be less than pages - 1.
+        // page must be less than pages - 1, unless the page is a non-circular empty page beyond the last page linking back to it.
         if self.get_page().gt(&(self.get_pages() - 1)) {
-            return Err(PaginationError::from(ErrorKind::FieldValueError(format!(
-                "Page index '{}' exceeds total pages '{}'",
-                self.get_page(),
-                self.get_pages(),
-            ))));
+            return match !circular && self.is_beyond_range() {
+                true => Ok(()),
+                false => Err(PaginationError::from(ErrorKind::OutOfRangePage {
+                    requested: self.get_page(),
+                    total_pages: self.get_pages(),
+                })),
+            };
         }
 
         // if page is less than pages - 1, items length must be equal to size.
@@ -123,22 +267,27 @@ impl<E> Page<E> {
             ))));
         }
 
-        // if page is equal to pages - 1, total must be equal to (pages - 1) * size + items length.
-        if self.get_page().eq(&(self.get_pages() - 1))
-            && self
-                .get_total()
-                .ne(&((self.get_pages() - 1) * self.get_size() + items_length))
-        {
-            return Err(PaginationError::from(ErrorKind::FieldValueError(format!(
-                "Total elements error: expected '{}', found '{}'",
-                (self.get_pages() - 1) * self.get_size() + items_length,
-                self.get_total(),
-            ))));
+        // if page is equal to pages - 1, total must be equal to (pages - 1) * size + items length, which must not overflow.
+        if self.get_page().eq(&(self.get_pages() - 1)) {
+            let expected_total: Option<usize> = (self.get_pages() - 1)
+                .checked_mul(self.get_size())
+                .and_then(|offset| offset.checked_add(items_length));
+
+            if expected_total.ne(&Some(self.get_total())) {
+                return Err(PaginationError::from(ErrorKind::FieldValueError(format!(
+                    "Total elements error: expected '{}', found '{}'",
+                    match expected_total {
+                        Some(expected_total) => format!("{}", expected_total),
+                        None => String::from("overflow"),
+                    },
+                    self.get_total(),
+                ))));
+            }
         }
 
-        // Previous page index must be equal to page - 1 if page is greater than 0, otherwise it must be None.
+        // Previous page index must be equal to page - 1 if page is greater than 0, otherwise it must be None, or pages - 1 for a circular page.
         let expected_previous_page: Option<usize> = match self.get_page().eq(&0) {
-            true => None,
+            true => Page::<E>::wrap_around_link(circular, self.get_pages(), self.get_pages() - 1),
             false => Some(self.get_page() - 1),
         };
 
@@ -150,9 +299,9 @@ impl<E> Page<E> {
             ))));
         }
 
-        // Next page index must be equal to page + 1 if page is less than pages - 1, otherwise it must be None.
+        // Next page index must be equal to page + 1 if page is less than pages - 1, otherwise it must be None, or 0 for a circular page.
         let expected_next_page: Option<usize> = match self.get_page().eq(&(self.get_pages() - 1)) {
-            true => None,
+            true => Page::<E>::wrap_around_link(circular, self.get_pages(), 0),
             false => Some(self.get_page() + 1),
         };
 
@@ -167,6 +316,22 @@ impl<E> Page<E> {
         Ok(())
     }
 
+    /// Check if the [`Page`] is an empty page beyond the last page, linking back to the last page and without next page.
+    fn is_beyond_range(&self) -> bool {
+        self.get_page().gt(&(self.get_pages() - 1))
+            && self.get_items().is_empty()
+            && self.get_previous_page().eq(&Some(self.get_pages() - 1))
+            && self.get_next_page().is_none()
+    }
+
+    /// Get the link of a boundary page, which only wraps around to **target** for a circular page with more than one page.
+    fn wrap_around_link(circular: bool, pages: usize, target: usize) -> Option<usize> {
+        match circular && pages.gt(&1) {
+            true => Some(target),
+            false => None,
+        }
+    }
+
     /// Create a new [`Page`] instance.
     ///
     /// ### Arguments:
@@ -203,144 +368,1473 @@ impl<E> Page<E> {
     where
         E: Clone,
     {
-        let pages: usize = match size.eq(&0) {
-            true => 1,
-            false => total.div_ceil(size).max(1),
-        };
-
-        let page: Page<E> = Page {
-            items: items.to_owned(),
-            page,
-            size,
-            total,
-            pages,
-            previous_page: match page.eq(&0) {
-                true => None,
-                false => Some(page - 1),
-            },
-            next_page: match page.eq(&(pages - 1)) {
-                true => None,
-                false => Some(page + 1),
-            },
-        };
-        page.verify_fields()?;
-
-        Ok(page)
+        Page::from_parts(items.to_owned(), page, size, total)
     }
-}
 
-/// Implementation of [`Clone`] for [`Page`].
-impl<E> Clone for Page<E>
-where
-    E: Clone,
-{
-    fn clone(&self) -> Self {
-        Page {
-            items: self.items.to_owned(),
-            page: self.page,
-            size: self.size,
-            total: self.total,
-            pages: self.pages,
-            previous_page: self.previous_page,
-            next_page: self.next_page,
+    /// Create a new circular [`Page`] instance, whose boundary pages link to each other for wrap-around navigation such as a carousel.
+    ///
+    /// ### Arguments:
+    /// - **items**: A reference to a collection of items `E`, where `E` must implement [`Clone`].
+    /// - **page**: The page index.
+    /// - **size**: The maximum number of elements per page.
+    /// - **total**: The total number of records used for pagination.
+    ///
+    /// ### Returns:
+    /// A [`PaginationResult`] with a [`Page`] if successful, otherwise a [`PaginationError`] is returned.
+    ///
+    /// When there is more than one page, the ***previous_page*** of the first page is ***pages*** - 1 and the ***next_page*** of the last page is 0. This deliberately violates the invariants checked by [`Page::verify_fields`], so a circular [`Page`] must be verified with [`Page::verify_fields_circular`] and is rejected by deserialization and by methods that verify the fields again. When there is a single page, both links are [`None`].
+    ///
+    /// ### Example:
+    ///```rust,no_run
+    /// use page_hunter::*;
+    ///
+    /// let page: Page<u32> = Page::new_circular(&vec![5], 2, 2, 5).unwrap_or_else(|error| {
+    ///     panic!("Error creating page model: {:?}", error);
+    /// });
+    ///
+    /// assert_eq!(page.get_next_page(), Some(0));
+    /// ````
+    pub fn new_circular(
+        items: &Vec<E>,
+        page: usize,
+        size: usize,
+        total: usize,
+    ) -> PaginationResult<Page<E>>
+    where
+        E: Clone,
+    {
+        let mut circular_page: Page<E> = Page::new(items, page, size, total)?;
+
+        if circular_page.get_page().eq(&0) {
+            circular_page.previous_page = Page::<E>::wrap_around_link(
+                true,
+                circular_page.get_pages(),
+                circular_page.get_pages() - 1,
+            );
+        }
+        if circular_page
+            .get_page()
+            .eq(&(circular_page.get_pages() - 1))
+        {
+            circular_page.next_page =
+                Page::<E>::wrap_around_link(true, circular_page.get_pages(), 0);
         }
-    }
-}
 
-/// Implementation of [`Debug`] for [`Page`].
-impl<E> Debug for Page<E>
-where
-    E: Debug,
-{
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(
-            f,
-            "Page {{ items: {:?}, page: {}, size: {}, total: {}, pages: {}, previous_page: {:?}, next_page: {:?} }}",
-            self.items, self.page, self.size, self.total, self.pages, self.previous_page, self.next_page
-        )
+        Ok(circular_page)
     }
-}
 
-/// Implementation of [`Default`] for [`Page`].
-impl<E> Default for Page<E> {
-    fn default() -> Self {
-        Self {
+    /// Create an empty [`Page`] instance.
+    ///
+    /// ### Arguments:
+    /// - **size**: The maximum number of elements per page.
+    ///
+    /// ### Returns:
+    /// A [`Page`] without items at ***page*** 0, with ***total*** 0 and a single page.
+    ///
+    /// ### Example:
+    ///```rust,no_run
+    /// use page_hunter::*;
+    ///
+    /// let page: Page<u32> = Page::empty(10);
+    /// ````
+    pub fn empty(size: usize) -> Page<E> {
+        Page {
             items: Vec::new(),
             page: 0,
-            size: 0,
+            size,
             total: 0,
             pages: 1,
             previous_page: None,
             next_page: None,
         }
     }
-}
 
-/// Implementation of [`Display`] for [`Page`].
-impl<E> Display for Page<E>
-where
-    E: Debug,
-{
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(
-            f,
-            "Page {{ items: {:?}, page: {}, size: {}, total: {}, pages: {}, previous_page: {:?}, next_page: {:?} }}",
-            self.items, self.page, self.size, self.total, self.pages, self.previous_page, self.next_page
-        )
-    }
-}
+    /// Create a new [`Page`] instance, adjusting ***total*** on the last page when it does not match the items.
+    ///
+    /// ### Arguments:
+    /// - **items**: A reference to a collection of items `E`, where `E` must implement [`Clone`].
+    /// - **page**: The page index.
+    /// - **size**: The maximum number of elements per page.
+    /// - **total**: The total number of records used for pagination.
+    ///
+    /// ### Returns:
+    /// A [`PaginationResult`] with a tuple of the [`Page`] and a [`bool`] that is `true` when ***total*** was adjusted, otherwise a [`PaginationError`] is returned.
+    ///
+    /// When the fields are valid, the [`Page`] is created as in [`Page::new`]. Otherwise, if **page** is the last page for **total** and would still be the last page with ***total*** equal to **page** * **size** + **items** length, that total is used instead. This tolerates a slightly off total caused by concurrent inserts or deletes in eventually-consistent systems, while wildly inconsistent fields are still rejected with the error of [`Page::new`].
+    ///
+    /// ### Example:
+    ///```rust,no_run
+    /// use page_hunter::*;
+    ///
+    /// let pagination_result: PaginationResult<(Page<u32>, bool)> =
+    ///     Page::new_lenient(&vec![13, 14, 15], 3, 4, 16);
+    ///
+    /// let (page, adjusted): (Page<u32>, bool) = pagination_result.unwrap_or_else(|error| {
+    ///     panic!("Error creating page model: {:?}", error);
+    /// });
+    /// ````
+    pub fn new_lenient(
+        items: &Vec<E>,
+        page: usize,
+        size: usize,
+        total: usize,
+    ) -> PaginationResult<(Page<E>, bool)>
+    where
+        E: Clone,
+    {
+        let error: PaginationError = match Page::new(items, page, size, total) {
+            Ok(page) => return Ok((page, false)),
+            Err(error) => error,
+        };
 
-/// Implementation of [`IntoIterator`] for [`Page`].
-impl<E> IntoIterator for Page<E> {
-    type Item = E;
-    type IntoIter = std::vec::IntoIter<Self::Item>;
+        let pages: usize = Page::<E>::page_count_for_total(total, size);
+        if items.len().gt(&size.max(1)) || page.ne(&(pages - 1)) {
+            return Err(error);
+        }
 
-    fn into_iter(self) -> Self::IntoIter {
-        self.items.into_iter()
+        let adjusted_total: usize = match page
+            .checked_mul(size)
+            .and_then(|offset| offset.checked_add(items.len()))
+        {
+            Some(adjusted_total) => adjusted_total,
+            None => return Err(error),
+        };
+
+        if pages.ne(&Page::<E>::page_count_for_total(adjusted_total, size)) {
+            return Err(error);
+        }
+
+        Ok((Page::new(items, page, size, adjusted_total)?, true))
     }
-}
 
-/// Implementation of [`Serialize`] for [`Page`] if the feature `serde` is enabled.
-#[cfg(feature = "serde")]
-impl<E> Serialize for Page<E>
-where
-    E: Serialize,
-{
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    /// Create a new [`Page`] instance from an iterator of items.
+    ///
+    /// ### Arguments:
+    /// - **items**: An iterator of items `E`.
+    /// - **page**: The page index.
+    /// - **size**: The maximum number of elements per page.
+    /// - **total**: The total number of records used for pagination.
+    ///
+    /// ### Returns:
+    /// A [`PaginationResult`] with a [`Page`] if successful, otherwise a [`PaginationError`] is returned.
+    ///
+    /// The items are collected into a [`Vec`] pre-allocated with ***size*** capacity, bounded by the upper size hint of the iterator, or by its lower size hint when the upper one is unknown, so a huge ***size*** never allocates more than the iterator can yield. This avoids reallocations and the clone performed by [`Page::new`].
+    ///
+    /// ### Example:
+    ///```rust,no_run
+    /// use page_hunter::*;
+    ///
+    /// let records: Vec<u32> = vec![1, 2, 3, 4, 5];
+    ///
+    /// let pagination_result: PaginationResult<Page<u32>> =
+    ///     Page::new_in(records.into_iter().skip(2).take(2), 1, 2, 5);
+    /// ````
+    pub fn new_in<I>(items: I, page: usize, size: usize, total: usize) -> PaginationResult<Page<E>>
     where
-        S: Serializer,
+        I: IntoIterator<Item = E>,
     {
-        #[derive(Serialize)]
-        struct PageModel<'a, E>
-        where
-            E: Serialize,
-        {
-            items: &'a Vec<E>,
-            page: usize,
-            size: usize,
-            total: usize,
-            pages: usize,
-            previous_page: Option<usize>,
-            next_page: Option<usize>,
-        }
+        let items_iter: I::IntoIter = items.into_iter();
 
-        let page_model: PageModel<E> = PageModel {
-            items: &self.items,
-            page: self.page,
-            size: self.size,
-            total: self.total,
-            pages: self.pages,
-            previous_page: self.previous_page,
-            next_page: self.next_page,
+        let capacity: usize = match items_iter.size_hint() {
+            (_, Some(upper)) => size.min(upper),
+            (lower, None) => size.min(lower),
         };
 
-        page_model.serialize(serializer)
+        let mut collected_items: Vec<E> = Vec::with_capacity(capacity);
+        collected_items.extend(items_iter);
+
+        Page::from_parts(collected_items, page, size, total)
     }
-}
 
-/// Implementation of [`Deserialize`] for [`Page`] if the feature `serde` is enabled.
-#[cfg(feature = "serde")]
-impl<'de, E> DeDeserialize<'de> for Page<E>
+    /// Create a new [`Page`] instance collecting the items of an iterator with a known length.
+    ///
+    /// ### Arguments:
+    /// - **items**: An iterator of items `E`, where the iterator must implement [`ExactSizeIterator`].
+    /// - **page**: The page index.
+    /// - **size**: The maximum number of elements per page.
+    /// - **total**: The total number of records used for pagination.
+    ///
+    /// ### Returns:
+    /// A [`PaginationResult`] with a [`Page`] if successful, otherwise a [`PaginationError`] is returned.
+    ///
+    /// The items are collected into a [`Vec`] allocated with the exact length of the iterator, without the clone performed by [`Page::new`]. This is useful when the items come from an iterator such as mapped database rows.
+    ///
+    /// ### Example:
+    ///```rust,no_run
+    /// use page_hunter::*;
+    ///
+    /// let rows: Vec<(u32, &str)> = vec![(1, "a"), (2, "b")];
+    ///
+    /// let pagination_result: PaginationResult<Page<u32>> =
+    ///     Page::from_exact(rows.iter().map(|(id, _)| *id), 0, 2, 5);
+    /// ````
+    pub fn from_exact<I>(
+        items: I,
+        page: usize,
+        size: usize,
+        total: usize,
+    ) -> PaginationResult<Page<E>>
+    where
+        I: ExactSizeIterator<Item = E>,
+    {
+        let mut collected_items: Vec<E> = Vec::with_capacity(items.len());
+        collected_items.extend(items);
+
+        Page::from_parts(collected_items, page, size, total)
+    }
+
+    /// Create a new [`Page`] instance from an iterator of fallible items, such as records decoded from custom row types.
+    ///
+    /// ### Arguments:
+    /// - **results**: An iterator of [`Result`] items, where the error `Er` must implement [`From`]<[`PaginationError`]>.
+    /// - **page**: The page index.
+    /// - **size**: The maximum number of elements per page.
+    /// - **total**: The total number of records used for pagination.
+    ///
+    /// ### Returns:
+    /// A [`Result`] with a [`Page`] if successful. Otherwise, the first error found in **results** is returned without consuming the remaining items, or the [`PaginationError`] of the fields verification converted into `Er`.
+    ///
+    /// ### Example:
+    ///```rust,no_run
+    /// use page_hunter::*;
+    ///
+    /// let rows: Vec<&str> = vec!["1", "2"];
+    ///
+    /// let pagination_result: PaginationResult<Page<u32>> = Page::from_results(
+    ///     rows.into_iter().map(|row| {
+    ///         row.parse::<u32>().map_err(|error| {
+    ///             PaginationError::from(ErrorKind::FieldValueError(error.to_string()))
+    ///         })
+    ///     }),
+    ///     0,
+    ///     2,
+    ///     5,
+    /// );
+    /// ````
+    pub fn from_results<I, Er>(
+        results: I,
+        page: usize,
+        size: usize,
+        total: usize,
+    ) -> Result<Page<E>, Er>
+    where
+        I: IntoIterator<Item = Result<E, Er>>,
+        Er: From<PaginationError>,
+    {
+        let items: Vec<E> = results.into_iter().collect::<Result<Vec<E>, Er>>()?;
+
+        Ok(Page::from_parts(items, page, size, total)?)
+    }
+
+    /// Create a new [`Page`] instance from its minimal parts, taking ownership of the items.
+    ///
+    /// ### Arguments:
+    /// - **items**: A [`Vec`] of items `E`.
+    /// - **page**: The page index.
+    /// - **size**: The maximum number of elements per page.
+    /// - **total**: The total number of records used for pagination.
+    ///
+    /// ### Returns:
+    /// A [`PaginationResult`] with a [`Page`] if successful, otherwise a [`PaginationError`] is returned.
+    ///
+    /// Like [`Page::new`], ***pages***, ***previous_page*** and ***next_page*** are computed and the fields are verified, but the items are moved instead of cloned. This is useful when the items and the pagination parameters come separately, e.g. a JSON array in the response body with ***page***, ***size*** and ***total*** in the headers.
+    ///
+    /// ### Example:
+    ///```rust,no_run
+    /// use page_hunter::*;
+    ///
+    /// let items: Vec<String> = vec![String::from("a"), String::from("b")];
+    ///
+    /// let page: Page<String> = Page::from_parts(items, 0, 2, 5).unwrap_or_else(|error| {
+    ///     panic!("Error creating page model: {:?}", error);
+    /// });
+    /// ````
+    pub fn from_parts(
+        items: Vec<E>,
+        page: usize,
+        size: usize,
+        total: usize,
+    ) -> PaginationResult<Page<E>> {
+        let pages: usize = Page::<E>::page_count_for_total(total, size);
+
+        let page: Page<E> = Page {
+            items,
+            page,
+            size,
+            total,
+            pages,
+            previous_page: match page.eq(&0) {
+                true => None,
+                false => Some(page - 1),
+            },
+            next_page: match page.eq(&(pages - 1)) {
+                true => None,
+                false => page.checked_add(1),
+            },
+        };
+        page.verify_fields()?;
+
+        Ok(page)
+    }
+
+    /// Rebuild a [`Page`] from the ***page***, ***size*** and ***total*** of an existing one with new items, as [`Page::from_parts`] does, but keeping an empty [`Page`] beyond the last page, which passes [`Page::verify_fields`] but is rejected by the constructors.
+    fn rebuild(items: Vec<E>, page: usize, size: usize, total: usize) -> PaginationResult<Page<E>> {
+        let pages: usize = Page::<E>::page_count_for_total(total, size);
+
+        match page.gt(&(pages - 1)) && items.is_empty() {
+            true => Ok(Page::new_unchecked(
+                items,
+                page,
+                size,
+                total,
+                pages,
+                Some(pages - 1),
+                None,
+            )),
+            false => Page::from_parts(items, page, size, total),
+        }
+    }
+
+    /// Split a [`Page`] holding the full dataset into a [`Book`] with a new page size.
+    ///
+    /// ### Arguments:
+    /// - **new_size**: The maximum number of elements per page in the resulting [`Book`].
+    ///
+    /// ### Returns:
+    /// A [`PaginationResult`] with a [`Book`] if successful, otherwise a [`PaginationError`] is returned.
+    ///
+    /// The [`Page`] can only be split when it contains all the records used for pagination, that is, when ***items*** length is equal to ***total***. Otherwise some records would be missing from the [`Book`] and an error is returned.
+    ///
+    /// ### Example:
+    ///```rust,no_run
+    /// use page_hunter::*;
+    ///
+    /// let page: Page<u32> = Page::new(&vec![1, 2, 3, 4, 5], 0, 5, 5).unwrap_or_else(|error| {
+    ///     panic!("Error creating page model: {:?}", error);
+    /// });
+    ///
+    /// let book_result: PaginationResult<Book<u32>> = page.split(2);
+    /// ````
+    pub fn split(self, new_size: usize) -> PaginationResult<Book<E>>
+    where
+        E: Clone,
+    {
+        if self.get_items().len().ne(&self.get_total()) {
+            return Err(PaginationError::from(ErrorKind::FieldValueError(format!(
+                "Page cannot be split: items length '{}' is not equal to total elements '{}'",
+                self.get_items().len(),
+                self.get_total(),
+            ))));
+        }
+
+        bind_records(&self.items, new_size)
+    }
+
+    /// Remove consecutive duplicate items from the current [`Page`], like [`Vec::dedup_by`].
+    ///
+    /// ### Arguments:
+    /// - **same**: A closure that returns `true` when two consecutive items are duplicates. The later item is removed.
+    ///
+    /// ### Returns:
+    /// *No returns*
+    ///
+    /// Only the in-memory ***items*** are affected: ***page***, ***size***, ***total***, ***pages***, ***previous_page*** and ***next_page*** are left intact, so the [`Page`] can end up with fewer items than ***size***.
+    ///
+    /// ### Example:
+    ///```rust,no_run
+    /// use page_hunter::*;
+    ///
+    /// let mut page: Page<u32> = Page::new(&vec![1, 1, 2, 2], 0, 4, 10).unwrap_or_else(|error| {
+    ///     panic!("Error creating page model: {:?}", error);
+    /// });
+    ///
+    /// page.dedup_by(|a, b| a == b);
+    /// ````
+    pub fn dedup_by<F>(&mut self, mut same: F)
+    where
+        F: FnMut(&E, &E) -> bool,
+    {
+        self.items.dedup_by(|a, b| same(a, b));
+    }
+
+    /// Rebuild the current [`Page`] from its ***items***, which is useful to get a valid [`Page`] after filtering its items in place, e.g. with [`Page::get_items_mut`].
+    ///
+    /// ### Arguments:
+    /// *No arguments*
+    ///
+    /// ### Returns:
+    /// A single self-contained [`Page`] holding the current ***items***, at ***page*** 0 with ***size*** and ***total*** equal to the number of items, 1 in ***pages***, and without ***previous_page*** nor ***next_page***.
+    ///
+    /// ### Example:
+    ///```rust,no_run
+    /// use page_hunter::*;
+    ///
+    /// let mut page: Page<u32> = Page::new(&vec![1, 2, 3, 4], 1, 4, 10).unwrap_or_else(|error| {
+    ///     panic!("Error creating page model: {:?}", error);
+    /// });
+    ///
+    /// page.get_items_mut().retain(|item| item % 2 == 0);
+    ///
+    /// let page: Page<u32> = page.reindex();
+    /// ````
+    pub fn reindex(self) -> Page<E> {
+        self.items.into_iter().collect()
+    }
+
+    /// Get the number of pages needed to hold ***total*** elements with a given ***size***.
+    ///
+    /// ### Arguments:
+    /// - **total**: The total number of records used for pagination.
+    /// - **size**: The maximum number of elements per page.
+    ///
+    /// ### Returns:
+    /// The number of pages, which is ***total*** divided by ***size*** rounded up. There is always at least one page, even when ***total*** or ***size*** is 0.
+    ///
+    /// ### Example:
+    ///```rust,no_run
+    /// use page_hunter::*;
+    ///
+    /// let pages: usize = Page::<u32>::page_count_for_total(101, 3);
+    /// ````
+    pub fn page_count_for_total(total: usize, size: usize) -> usize {
+        match size.eq(&0) {
+            true => 1,
+            false => total.div_ceil(size).max(1),
+        }
+    }
+
+    /// Check if a page index exists for a given ***total*** and ***size***, which is useful to validate a requested page without building a [`Page`].
+    ///
+    /// ### Arguments:
+    /// - **page**: The page index to check.
+    /// - **total**: The total number of records used for pagination.
+    /// - **size**: The maximum number of elements per page.
+    ///
+    /// ### Returns:
+    /// `true` if **page** is lower than the number of pages returned by [`Page::page_count_for_total`], otherwise `false`. Page 0 always exists.
+    ///
+    /// ### Example:
+    ///```rust,no_run
+    /// use page_hunter::*;
+    ///
+    /// let is_valid: bool = Page::<u32>::is_valid_index(33, 101, 3);
+    /// ````
+    pub fn is_valid_index(page: usize, total: usize, size: usize) -> bool {
+        page.lt(&Page::<E>::page_count_for_total(total, size))
+    }
+
+    /// Get the number of pages remaining after the current [`Page`].
+    ///
+    /// ### Arguments:
+    /// *No arguments*
+    ///
+    /// ### Returns:
+    /// The number of pages after ***page***, which is 0 on the last page and beyond it.
+    ///
+    /// ### Example:
+    ///```rust,no_run
+    /// use page_hunter::*;
+    ///
+    /// let page: Page<u32> = Page::new(&vec![7, 8, 9], 2, 3, 101).unwrap_or_else(|error| {
+    ///     panic!("Error creating page model: {:?}", error);
+    /// });
+    ///
+    /// println!(
+    ///     "page {} of {}, {} remaining",
+    ///     page.get_page() + 1,
+    ///     page.get_pages(),
+    ///     page.remaining_pages()
+    /// );
+    /// ````
+    pub fn remaining_pages(&self) -> usize {
+        self.get_pages()
+            .saturating_sub(self.get_page())
+            .saturating_sub(1)
+    }
+
+    /// Combine the items of two [`Page`] instances element-wise into a new [`Page`].
+    ///
+    /// ### Arguments:
+    /// - **other**: The [`Page`] whose items are paired with the items of the current [`Page`].
+    /// - **f**: A closure that combines an item of the current [`Page`] with the item of **other** at the same position.
+    ///
+    /// ### Returns:
+    /// A [`PaginationResult`] with a [`Page`] of the combined items if successful, otherwise a [`PaginationError`] is returned.
+    ///
+    /// Both pages must share ***page***, ***size*** and ***total***, and must have the same number of items. Otherwise an error is returned.
+    ///
+    /// ### Example:
+    ///```rust,no_run
+    /// use page_hunter::*;
+    ///
+    /// let users: Page<&str> = Page::new(&vec!["alice", "bob"], 0, 2, 5).unwrap_or_else(|error| {
+    ///     panic!("Error creating page model: {:?}", error);
+    /// });
+    /// let scores: Page<f32> = Page::new(&vec![0.7, 0.4], 0, 2, 5).unwrap_or_else(|error| {
+    ///     panic!("Error creating page model: {:?}", error);
+    /// });
+    ///
+    /// let scored_users_result: PaginationResult<Page<(&str, f32)>> =
+    ///     users.zip_with(scores, |user, score| (user, score));
+    /// ````
+    pub fn zip_with<U, T, F>(self, other: Page<U>, mut f: F) -> PaginationResult<Page<T>>
+    where
+        F: FnMut(E, U) -> T,
+    {
+        if self.get_page().ne(&other.get_page())
+            || self.get_size().ne(&other.get_size())
+            || self.get_total().ne(&other.get_total())
+        {
+            return Err(PaginationError::from(ErrorKind::FieldValueError(format!(
+                "Pages cannot be zipped: expected page '{}', size '{}' and total '{}', found page '{}', size '{}' and total '{}'",
+                self.get_page(),
+                self.get_size(),
+                self.get_total(),
+                other.get_page(),
+                other.get_size(),
+                other.get_total(),
+            ))));
+        }
+
+        if self.get_items().len().ne(&other.get_items().len()) {
+            return Err(PaginationError::from(ErrorKind::FieldValueError(format!(
+                "Pages cannot be zipped: items length '{}' is not equal to items length '{}'",
+                self.get_items().len(),
+                other.get_items().len(),
+            ))));
+        }
+
+        let (page, size, total): (usize, usize, usize) =
+            (self.get_page(), self.get_size(), self.get_total());
+
+        Page::rebuild(
+            self.items
+                .into_iter()
+                .zip(other.items)
+                .map(|(item, other_item)| f(item, other_item))
+                .collect(),
+            page,
+            size,
+            total,
+        )
+    }
+
+    /// Serialize the [`Page`] into a canonical JSON string. Only available when the `serde` feature is enabled.
+    ///
+    /// ### Arguments:
+    /// *No arguments*
+    ///
+    /// ### Returns:
+    /// A [`Result`] with the JSON string if successful, otherwise a [`serde_json::Error`] is returned.
+    ///
+    /// The output is compact and its keys always follow the order ***items***, ***page***, ***size***, ***total***, ***pages***, ***previous_page***, ***next_page***, so two serializations of the same [`Page`] are byte-identical. This makes it suitable for snapshot testing, as long as the serialization of `E` is deterministic too.
+    ///
+    /// ### Example:
+    ///```rust,no_run
+    /// use page_hunter::*;
+    ///
+    /// let page: Page<u32> = Page::new(&vec![1, 2], 0, 2, 5).unwrap_or_else(|error| {
+    ///     panic!("Error creating page model: {:?}", error);
+    /// });
+    ///
+    /// let json: Result<String, serde_json::Error> = page.to_canonical_json();
+    /// ````
+    #[cfg(feature = "serde")]
+    pub fn to_canonical_json(&self) -> Result<String, serde_json::Error>
+    where
+        E: Serialize,
+    {
+        serde_json::to_string(self)
+    }
+
+    /// Deserialize a [`Page`] recomputing its derived fields. Only available when the `serde` feature is enabled.
+    ///
+    /// ### Arguments:
+    /// - **deserializer**: A [`serde::Deserializer`] of the [`Page`].
+    ///
+    /// ### Returns:
+    /// A [`Result`] with the [`Page`] if successful, otherwise a `D::Error` is returned.
+    ///
+    /// Only ***items***, ***page***, ***size*** and ***total*** are read. The incoming ***pages***, ***previous_page*** and ***next_page*** are ignored, and may be missing or wrong, since they are recomputed as in [`Page::from_parts`], which also verifies that the number of ***items*** is consistent with the other fields. An empty [`Page`] beyond the last page is accepted as by [`Page::verify_fields`]. It can be used with `#[serde(deserialize_with = "Page::deserialize_recompute")]` to accept payloads from clients that omit or miscompute the derived fields, while the [`Deserialize`] implementation of [`Page`] remains strict.
+    ///
+    /// ### Example:
+    ///```rust,no_run
+    /// use page_hunter::*;
+    ///
+    /// let mut deserializer = serde_json::Deserializer::from_str(
+    ///     r#"{"items": [3, 4], "page": 1, "size": 2, "total": 5, "next_page": 7}"#,
+    /// );
+    ///
+    /// let page: Page<u32> = Page::deserialize_recompute(&mut deserializer).unwrap_or_else(|error| {
+    ///     panic!("Error deserializing page model: {:?}", error);
+    /// });
+    /// ````
+    #[cfg(feature = "serde")]
+    pub fn deserialize_recompute<'de, D>(deserializer: D) -> Result<Page<E>, D::Error>
+    where
+        E: Deserialize<'de>,
+        D: DeDeserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        struct PageParts<E> {
+            items: Vec<E>,
+            page: usize,
+            size: usize,
+            total: usize,
+        }
+
+        let parts: PageParts<E> = DeDeserialize::deserialize(deserializer)?;
+
+        Page::rebuild(parts.items, parts.page, parts.size, parts.total).map_err(DeError::custom)
+    }
+
+    /// Convert the [`Page`] into a [`Book`] with a single sheet.
+    ///
+    /// ### Arguments:
+    /// *No arguments*
+    ///
+    /// ### Returns:
+    /// A [`Book`] whose only sheet is the current [`Page`].
+    ///
+    /// ### Example:
+    ///```rust,no_run
+    /// use page_hunter::*;
+    ///
+    /// let page: Page<u32> = Page::new(&vec![1, 2], 0, 2, 5).unwrap_or_else(|error| {
+    ///     panic!("Error creating page model: {:?}", error);
+    /// });
+    ///
+    /// let book: Book<u32> = page.into_book();
+    /// ````
+    pub fn into_book(self) -> Book<E> {
+        Book { sheets: vec![self] }
+    }
+
+    /// Serialize the [`Page`] into bytes using [`bincode`]. Only available when the `bincode` feature is enabled.
+    ///
+    /// ### Arguments:
+    /// *No arguments*
+    ///
+    /// ### Returns:
+    /// A [`Result`] with the encoded bytes if successful, otherwise a [`bincode::Error`] is returned.
+    ///
+    /// Every field is encoded, even when the feature `serde-skip-null-links` is enabled, since bincode decodes the fields by position.
+    ///
+    /// ### Example:
+    ///```rust,no_run
+    /// use page_hunter::*;
+    ///
+    /// let page: Page<u32> = Page::new(&vec![1, 2], 0, 2, 5).unwrap_or_else(|error| {
+    ///     panic!("Error creating page model: {:?}", error);
+    /// });
+    ///
+    /// let bytes: Result<Vec<u8>, bincode::Error> = page.to_bytes();
+    /// ````
+    #[cfg(feature = "bincode")]
+    pub fn to_bytes(&self) -> Result<Vec<u8>, bincode::Error>
+    where
+        E: Serialize,
+    {
+        bincode::serialize(&BincodePage {
+            items: &self.items,
+            page: self.page,
+            size: self.size,
+            total: self.total,
+            pages: self.pages,
+            previous_page: self.previous_page,
+            next_page: self.next_page,
+        })
+    }
+
+    /// Deserialize a [`Page`] from bytes encoded with [`Page::to_bytes`]. Only available when the `bincode` feature is enabled.
+    ///
+    /// ### Arguments:
+    /// - **bytes**: The bytes encoded with [`Page::to_bytes`].
+    ///
+    /// ### Returns:
+    /// A [`Result`] with the decoded [`Page`] if successful, otherwise a [`bincode::Error`] is returned.
+    ///
+    /// The decoded fields are verified like in the JSON deserialization, so a corrupted payload describing an invalid [`Page`] is rejected with the [`PaginationError`] message.
+    ///
+    /// ### Example:
+    ///```rust,no_run
+    /// use page_hunter::*;
+    ///
+    /// let page: Page<u32> = Page::new(&vec![1, 2], 0, 2, 5).unwrap_or_else(|error| {
+    ///     panic!("Error creating page model: {:?}", error);
+    /// });
+    /// let bytes: Vec<u8> = page.to_bytes().unwrap_or_else(|error| {
+    ///     panic!("Error encoding page model: {:?}", error);
+    /// });
+    ///
+    /// let decoded_page: Result<Page<u32>, bincode::Error> = Page::from_bytes(&bytes);
+    /// ````
+    #[cfg(feature = "bincode")]
+    pub fn from_bytes<'de>(bytes: &'de [u8]) -> Result<Page<E>, bincode::Error>
+    where
+        E: Deserialize<'de>,
+    {
+        bincode::deserialize(bytes)
+    }
+
+    /// Map the items of the [`Page`] into a new [`Page`], checking that the number of items is preserved.
+    ///
+    /// ### Arguments:
+    /// - **f**: A closure that maps each item `E` into an item `T`.
+    ///
+    /// ### Returns:
+    /// A [`PaginationResult`] with a [`Page`] of the mapped items and the same ***page***, ***size*** and ***total*** if successful, otherwise a [`PaginationError`] is returned.
+    ///
+    /// An error is returned if the number of mapped items differs from the number of original items, so the pagination fields are never attached to a different set of records.
+    ///
+    /// ### Example:
+    ///```rust,no_run
+    /// use page_hunter::*;
+    ///
+    /// let page: Page<u32> = Page::new(&vec![1, 2], 0, 2, 5).unwrap_or_else(|error| {
+    ///     panic!("Error creating page model: {:?}", error);
+    /// });
+    ///
+    /// let mapped_page_result: PaginationResult<Page<String>> =
+    ///     page.checked_map(|item| item.to_string());
+    /// ````
+    pub fn checked_map<T, F>(self, f: F) -> PaginationResult<Page<T>>
+    where
+        F: FnMut(E) -> T,
+    {
+        let (items_length, page, size, total): (usize, usize, usize, usize) = (
+            self.get_items().len(),
+            self.get_page(),
+            self.get_size(),
+            self.get_total(),
+        );
+
+        let items: Vec<T> = self.items.into_iter().map(f).collect();
+
+        if items.len().ne(&items_length) {
+            return Err(PaginationError::from(ErrorKind::FieldValueError(format!(
+                "Mapped items length '{}' is not equal to original items length '{}'",
+                items.len(),
+                items_length,
+            ))));
+        }
+
+        Page::rebuild(items, page, size, total)
+    }
+
+    /// Map the items of the [`Page`] into a new [`Page`], passing the absolute index of each record to the closure.
+    ///
+    /// ### Arguments:
+    /// - **f**: A closure that maps the absolute index of a record across all pages, which is ***page*** * ***size*** plus its position within the [`Page`], and the item `E` into an item `T`.
+    ///
+    /// ### Returns:
+    /// A [`Page`] of the mapped items with the same ***page***, ***size***, ***total***, ***pages***, ***previous_page*** and ***next_page***.
+    ///
+    /// The absolute index saturates at [`usize::MAX`] instead of overflowing, which can only happen on an empty [`Page`] beyond the last page, so the closure is never called with a saturated index.
+    ///
+    /// ### Example:
+    ///```rust,no_run
+    /// use page_hunter::*;
+    ///
+    /// let page: Page<&str> = Page::new(&vec!["carol", "dave"], 1, 2, 5).unwrap_or_else(|error| {
+    ///     panic!("Error creating page model: {:?}", error);
+    /// });
+    ///
+    /// let ranked_page: Page<(usize, &str)> = page.map_indexed(|index, name| (index + 1, name));
+    /// ````
+    pub fn map_indexed<T, F>(self, mut f: F) -> Page<T>
+    where
+        F: FnMut(usize, E) -> T,
+    {
+        let offset: usize = self.get_page().saturating_mul(self.get_size());
+
+        Page {
+            items: self
+                .items
+                .into_iter()
+                .enumerate()
+                .map(|(position, item)| f(offset.saturating_add(position), item))
+                .collect(),
+            page: self.page,
+            size: self.size,
+            total: self.total,
+            pages: self.pages,
+            previous_page: self.previous_page,
+            next_page: self.next_page,
+        }
+    }
+
+    /// Get the requests of the adjacent pages, which is useful to render previous and next links.
+    ///
+    /// ### Arguments:
+    /// *No arguments*
+    ///
+    /// ### Returns:
+    /// A tuple of the previous and the next page requests, where each one is [`Some`] with a tuple of the page index and ***size*** when the adjacent page exists, otherwise [`None`].
+    ///
+    /// ### Example:
+    ///```rust,no_run
+    /// use page_hunter::*;
+    ///
+    /// let page: Page<u32> = Page::new(&vec![3, 4], 1, 2, 5).unwrap_or_else(|error| {
+    ///     panic!("Error creating page model: {:?}", error);
+    /// });
+    ///
+    /// let (previous_request, next_request): PageNeighbors = page.neighbors();
+    /// ````
+    pub fn neighbors(&self) -> PageNeighbors {
+        (self.previous_params(), self.next_params())
+    }
+
+    /// Get the request of the previous page, which is useful to drive a "previous" button.
+    ///
+    /// ### Arguments:
+    /// *No arguments*
+    ///
+    /// ### Returns:
+    /// [`Some`] with a tuple of the ***previous_page*** index and ***size***, or [`None`] on the first page.
+    ///
+    /// ### Example:
+    ///```rust,no_run
+    /// use page_hunter::*;
+    ///
+    /// let page: Page<u32> = Page::new(&vec![3, 4], 1, 2, 5).unwrap_or_else(|error| {
+    ///     panic!("Error creating page model: {:?}", error);
+    /// });
+    ///
+    /// let previous_params: Option<(usize, usize)> = page.previous_params();
+    /// ````
+    pub fn previous_params(&self) -> Option<(usize, usize)> {
+        self.get_previous_page()
+            .map(|previous_page| (previous_page, self.get_size()))
+    }
+
+    /// Get the request of the next page, which is useful to drive a "next" button.
+    ///
+    /// ### Arguments:
+    /// *No arguments*
+    ///
+    /// ### Returns:
+    /// [`Some`] with a tuple of the ***next_page*** index and ***size***, or [`None`] on the last page.
+    ///
+    /// ### Example:
+    ///```rust,no_run
+    /// use page_hunter::*;
+    ///
+    /// let page: Page<u32> = Page::new(&vec![3, 4], 1, 2, 5).unwrap_or_else(|error| {
+    ///     panic!("Error creating page model: {:?}", error);
+    /// });
+    ///
+    /// let next_params: Option<(usize, usize)> = page.next_params();
+    /// ````
+    pub fn next_params(&self) -> Option<(usize, usize)> {
+        self.get_next_page()
+            .map(|next_page| (next_page, self.get_size()))
+    }
+
+    /// Estimate the memory used by the [`Page`], which is useful to enforce a byte budget in a cache of pages. Only available when the `deepsize` feature is enabled.
+    ///
+    /// ### Arguments:
+    /// *No arguments*
+    ///
+    /// ### Returns:
+    /// The estimated number of bytes used by the fields of the [`Page`] plus the heap allocations of its ***items***, where `E` must implement [`DeepSizeOf`]. The allocator overhead is not accounted for.
+    ///
+    /// ### Example:
+    ///```rust,no_run
+    /// use page_hunter::*;
+    ///
+    /// let page: Page<String> = Page::new(&vec![String::from("a"), String::from("b")], 0, 2, 5)
+    ///     .unwrap_or_else(|error| {
+    ///         panic!("Error creating page model: {:?}", error);
+    ///     });
+    ///
+    /// let heap_size: usize = page.heap_size();
+    /// ````
+    #[cfg(feature = "deepsize")]
+    pub fn heap_size(&self) -> usize
+    where
+        E: DeepSizeOf,
+    {
+        self.deep_size_of()
+    }
+
+    /// Take a random sample of the items of the [`Page`], which is useful for previews. Only available when the `rand` feature is enabled.
+    ///
+    /// ### Arguments:
+    /// - **n**: The maximum number of items to sample.
+    /// - **rng**: A mutable reference to a random number generator implementing [`Rng`].
+    ///
+    /// ### Returns:
+    /// A [`Vec`] with up to **n** distinct items chosen randomly from ***items***, where `E` must implement [`Clone`]. The sample is not returned as a [`Page`] because it breaks the pagination invariants.
+    ///
+    /// ### Example:
+    ///```rust,no_run
+    /// use page_hunter::*;
+    ///
+    /// let page: Page<u32> = Page::new(&vec![1, 2, 3, 4], 0, 4, 10).unwrap_or_else(|error| {
+    ///     panic!("Error creating page model: {:?}", error);
+    /// });
+    ///
+    /// let preview: Vec<u32> = page.sample(2, &mut rand::thread_rng());
+    /// ````
+    #[cfg(feature = "rand")]
+    pub fn sample<R>(&self, n: usize, rng: &mut R) -> Vec<E>
+    where
+        E: Clone,
+        R: Rng + ?Sized,
+    {
+        self.items.choose_multiple(rng, n).cloned().collect()
+    }
+
+    /// Replace the items of the [`Page`] keeping ***page***, ***size*** and ***total***, which is useful after a transformation that produces a new collection of records.
+    ///
+    /// ### Arguments:
+    /// - **items**: The new items of the [`Page`].
+    ///
+    /// ### Returns:
+    /// A [`PaginationResult`] with a [`Page`] of the new items if successful, otherwise a [`PaginationError`] is returned.
+    ///
+    /// The fields are verified again, so an error is returned if the length of the new items does not fit the ***page***, for example a wrong length on an intermediate page.
+    ///
+    /// ### Example:
+    ///```rust,no_run
+    /// use page_hunter::*;
+    ///
+    /// let page: Page<u32> = Page::new(&vec![3, 4], 1, 2, 5).unwrap_or_else(|error| {
+    ///     panic!("Error creating page model: {:?}", error);
+    /// });
+    ///
+    /// let replaced_page_result: PaginationResult<Page<u32>> = page.with_items(vec![30, 40]);
+    /// ````
+    pub fn with_items(self, items: Vec<E>) -> PaginationResult<Page<E>> {
+        Page::rebuild(items, self.page, self.size, self.total)
+    }
+
+    /// Get ***total*** converted into another integer type, which is useful when the records count is represented with a narrower type on the wire.
+    ///
+    /// ### Arguments:
+    /// *No arguments*
+    ///
+    /// ### Returns:
+    /// A [`Result`] with ***total*** as `T` if successful, otherwise the `T::Error` of the [`TryFrom`] conversion is returned when ***total*** does not fit in `T`.
+    ///
+    /// ### Example:
+    ///```rust,no_run
+    /// use page_hunter::*;
+    ///
+    /// let page: Page<u32> = Page::new(&vec![1, 2], 0, 2, 5).unwrap_or_else(|error| {
+    ///     panic!("Error creating page model: {:?}", error);
+    /// });
+    ///
+    /// let total: Result<u32, std::num::TryFromIntError> = page.total_as::<u32>();
+    /// ````
+    pub fn total_as<T>(&self) -> Result<T, T::Error>
+    where
+        T: TryFrom<usize>,
+    {
+        T::try_from(self.get_total())
+    }
+
+    /// Borrow the [`Page`] as a [`Page`] of references to its items, which is useful to pass it to code that only reads the records.
+    ///
+    /// ### Arguments:
+    /// *No arguments*
+    ///
+    /// ### Returns:
+    /// A [`Page`] of references to ***items*** with the same ***page***, ***size***, ***total***, ***pages***, ***previous_page*** and ***next_page***. The borrowed [`Page`] cannot outlive the original one.
+    ///
+    /// ### Example:
+    ///```rust,no_run
+    /// use page_hunter::*;
+    ///
+    /// let page: Page<String> = Page::new(&vec![String::from("a"), String::from("b")], 0, 2, 5)
+    ///     .unwrap_or_else(|error| {
+    ///         panic!("Error creating page model: {:?}", error);
+    ///     });
+    ///
+    /// let ref_page: Page<&String> = page.as_ref_page();
+    /// ````
+    pub fn as_ref_page(&self) -> Page<&E> {
+        Page {
+            items: self.items.iter().collect(),
+            page: self.page,
+            size: self.size,
+            total: self.total,
+            pages: self.pages,
+            previous_page: self.previous_page,
+            next_page: self.next_page,
+        }
+    }
+
+    /// Check whether the fields of the [`Page`] are valid, which is useful for assertions and guards that do not need the error.
+    ///
+    /// ### Arguments:
+    /// *No arguments*
+    ///
+    /// ### Returns:
+    /// `true` if [`Page::verify_fields`] succeeds, otherwise `false`.
+    ///
+    /// ### Example:
+    ///```rust,no_run
+    /// use page_hunter::*;
+    ///
+    /// let page: Page<u32> = Page::new(&vec![1, 2], 0, 2, 5).unwrap_or_else(|error| {
+    ///     panic!("Error creating page model: {:?}", error);
+    /// });
+    ///
+    /// let is_valid: bool = page.is_valid();
+    /// ````
+    pub fn is_valid(&self) -> bool {
+        self.verify_fields().is_ok()
+    }
+
+    /// Get an owned copy of the items of the [`Page`] without consuming it.
+    ///
+    /// ### Arguments:
+    /// *No arguments*
+    ///
+    /// ### Returns:
+    /// A [`Vec`] with a clone of ***items***, where `E` must implement [`Clone`].
+    ///
+    /// ### Example:
+    ///```rust,no_run
+    /// use page_hunter::*;
+    ///
+    /// let page: Page<u32> = Page::new(&vec![1, 2], 0, 2, 5).unwrap_or_else(|error| {
+    ///     panic!("Error creating page model: {:?}", error);
+    /// });
+    ///
+    /// let items: Vec<u32> = page.items_owned();
+    /// ````
+    pub fn items_owned(&self) -> Vec<E>
+    where
+        E: Clone,
+    {
+        self.items.clone()
+    }
+
+    /// Check whether the [`Page`] is the last page and it is exactly full, which is useful to tell a partial last page apart from one that fills ***size***.
+    ///
+    /// ### Arguments:
+    /// *No arguments*
+    ///
+    /// ### Returns:
+    /// `true` if ***page*** is the last page index, ***pages*** - 1, and ***items*** length is equal to ***size***, that is, ***total*** is a multiple of ***size***. Otherwise, including any page that is not the last one, `false`. The last page of a circular [`Page`] is detected as well, even though its ***next_page*** links back to the first page.
+    ///
+    /// ### Example:
+    ///```rust,no_run
+    /// use page_hunter::*;
+    ///
+    /// let page: Page<u32> = Page::new(&vec![3, 4], 1, 2, 4).unwrap_or_else(|error| {
+    ///     panic!("Error creating page model: {:?}", error);
+    /// });
+    ///
+    /// let last_page_is_full: bool = page.last_page_is_full();
+    /// ````
+    pub fn last_page_is_full(&self) -> bool {
+        self.get_page().eq(&self.get_pages().saturating_sub(1))
+            && self.get_items().len().eq(&self.get_size())
+    }
+
+    /// Get the metadata of the [`Page`] without its items.
+    ///
+    /// ### Returns:
+    /// A [`PageMetadata`] with the ***page***, ***size***, ***total***, ***pages***, ***previous_page*** and ***next_page*** fields of the [`Page`]. The items are not cloned.
+    ///
+    /// ### Example:
+    ///```rust,no_run
+    /// use page_hunter::*;
+    ///
+    /// let page: Page<u32> = Page::new(&vec![3, 4], 1, 2, 5).unwrap_or_else(|error| {
+    ///     panic!("Error creating page model: {:?}", error);
+    /// });
+    ///
+    /// let metadata: PageMetadata = page.metadata();
+    /// ````
+    pub fn metadata(&self) -> PageMetadata {
+        PageMetadata {
+            page: self.page,
+            size: self.size,
+            total: self.total,
+            pages: self.pages,
+            previous_page: self.previous_page,
+            next_page: self.next_page,
+        }
+    }
+
+    /// Split the [`Page`] into its items and its metadata, taking ownership of the items.
+    ///
+    /// ### Returns:
+    /// A tuple with the [`Vec`] of items and the [`PageMetadata`] of the [`Page`]. The items can be transformed separately and attached again with [`PageMetadata::attach`].
+    ///
+    /// ### Example:
+    ///```rust,no_run
+    /// use page_hunter::*;
+    ///
+    /// let page: Page<u32> = Page::new(&vec![3, 4], 1, 2, 5).unwrap_or_else(|error| {
+    ///     panic!("Error creating page model: {:?}", error);
+    /// });
+    ///
+    /// let (items, metadata): (Vec<u32>, PageMetadata) = page.split_off_items();
+    /// ````
+    pub fn split_off_items(self) -> (Vec<E>, PageMetadata) {
+        let metadata: PageMetadata = self.metadata();
+
+        (self.items, metadata)
+    }
+
+    /// Create a new [`Page`] instance from all its fields, without verifying them.
+    ///
+    /// ### Arguments:
+    /// - **items**: A [`Vec`] of items of the page.
+    /// - **page**: The page index.
+    /// - **size**: The maximum number of items per page.
+    /// - **total**: The total number of records used for pagination.
+    /// - **pages**: The total number of pages.
+    /// - **previous_page**: The previous page index, if any.
+    /// - **next_page**: The next page index, if any.
+    ///
+    /// ### Returns:
+    /// A [`Page`] with the given fields.
+    ///
+    /// ### Note: Fields are not verified:
+    /// This is an escape hatch for fields that are already known to be consistent, such as a page restored from a trusted cache. Unlike [`Page::new`], the number of pages and the links are not computed and [`Page::verify_fields`] is not called, so inconsistent fields produce a [`Page`] that breaks the invariants of the other methods. Call [`Page::verify_fields`] if the fields may be inconsistent.
+    ///
+    /// ### Example:
+    ///```rust,no_run
+    /// use page_hunter::*;
+    ///
+    /// let page: Page<u32> = Page::new_unchecked(vec![3, 4], 1, 2, 5, 3, Some(0), Some(2));
+    /// ````
+    pub fn new_unchecked(
+        items: Vec<E>,
+        page: usize,
+        size: usize,
+        total: usize,
+        pages: usize,
+        previous_page: Option<usize>,
+        next_page: Option<usize>,
+    ) -> Page<E> {
+        Page {
+            items,
+            page,
+            size,
+            total,
+            pages,
+            previous_page,
+            next_page,
+        }
+    }
+}
+
+impl<E> Page<Option<E>> {
+    /// Transpose a [`Page`] of [`Option`] items into an [`Option`] of a [`Page`].
+    ///
+    /// ### Arguments:
+    /// *No arguments*
+    ///
+    /// ### Returns:
+    /// [`Some`] with a [`Page`] of the unwrapped items if every item is [`Some`], otherwise [`None`]. The fields of the [`Page`] are preserved.
+    ///
+    /// ### Example:
+    ///```rust,no_run
+    /// use page_hunter::*;
+    ///
+    /// let page: Page<Option<u32>> = Page::new(&vec![Some(1), Some(2)], 0, 2, 5).unwrap_or_else(|error| {
+    ///     panic!("Error creating page model: {:?}", error);
+    /// });
+    ///
+    /// let transposed_page: Option<Page<u32>> = page.transpose();
+    /// ````
+    pub fn transpose(self) -> Option<Page<E>> {
+        Some(Page {
+            items: self.items.into_iter().collect::<Option<Vec<E>>>()?,
+            page: self.page,
+            size: self.size,
+            total: self.total,
+            pages: self.pages,
+            previous_page: self.previous_page,
+            next_page: self.next_page,
+        })
+    }
+}
+
+impl<E, Er> Page<Result<E, Er>> {
+    /// Transpose a [`Page`] of [`Result`] items into a [`Result`] of a [`Page`].
+    ///
+    /// ### Arguments:
+    /// *No arguments*
+    ///
+    /// ### Returns:
+    /// [`Ok`] with a [`Page`] of the unwrapped items if every item is [`Ok`], otherwise the first [`Err`] found. The fields of the [`Page`] are preserved.
+    ///
+    /// ### Example:
+    ///```rust,no_run
+    /// use page_hunter::*;
+    ///
+    /// let page: Page<Result<u32, String>> = Page::new(&vec![Ok(1), Ok(2)], 0, 2, 5).unwrap_or_else(|error| {
+    ///     panic!("Error creating page model: {:?}", error);
+    /// });
+    ///
+    /// let transposed_page: Result<Page<u32>, String> = page.transpose_result();
+    /// ````
+    pub fn transpose_result(self) -> Result<Page<E>, Er> {
+        Ok(Page {
+            items: self.items.into_iter().collect::<Result<Vec<E>, Er>>()?,
+            page: self.page,
+            size: self.size,
+            total: self.total,
+            pages: self.pages,
+            previous_page: self.previous_page,
+            next_page: self.next_page,
+        })
+    }
+}
+
+/// Implementation of [`Clone`] for [`Page`].
+impl<E> Clone for Page<E>
+where
+    E: Clone,
+{
+    fn clone(&self) -> Self {
+        Page {
+            items: self.items.to_owned(),
+            page: self.page,
+            size: self.size,
+            total: self.total,
+            pages: self.pages,
+            previous_page: self.previous_page,
+            next_page: self.next_page,
+        }
+    }
+}
+
+/// Implementation of [`Debug`] for [`Page`].
+impl<E> Debug for Page<E>
+where
+    E: Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "Page {{ items: {:?}, page: {}, size: {}, total: {}, pages: {}, previous_page: {:?}, next_page: {:?} }}",
+            self.items, self.page, self.size, self.total, self.pages, self.previous_page, self.next_page
+        )
+    }
+}
+
+/// Implementation of [`Default`] for [`Page`].
+impl<E> Default for Page<E> {
+    fn default() -> Self {
+        Self {
+            items: Vec::new(),
+            page: 0,
+            size: 0,
+            total: 0,
+            pages: 1,
+            previous_page: None,
+            next_page: None,
+        }
+    }
+}
+
+/// Implementation of [`PartialEq`] for [`Page`], comparing all the fields.
+impl<E> PartialEq for Page<E>
+where
+    E: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.items == other.items
+            && self.page == other.page
+            && self.size == other.size
+            && self.total == other.total
+            && self.pages == other.pages
+            && self.previous_page == other.previous_page
+            && self.next_page == other.next_page
+    }
+}
+
+/// Implementation of [`Eq`] for [`Page`].
+impl<E> Eq for Page<E> where E: Eq {}
+
+/// Implementation of [`PartialOrd`] for [`Page`].
+///
+/// Pages are ordered by ***page*** index only, so a collection of pages fetched out of order can be sorted before creating a [`Book`] whatever the type of the items. Pages with the same index compare as equal even when their other fields or ***items*** differ, so this ordering is not consistent with [`PartialEq`].
+impl<E> PartialOrd for Page<E>
+where
+    E: PartialEq,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.page.cmp(&other.page))
+    }
+}
+
+/// Implementation of [`Ord`] for [`Page`].
+///
+/// Pages are ordered by ***page*** index only, as in the [`PartialOrd`] implementation.
+impl<E> Ord for Page<E>
+where
+    E: Eq,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.page.cmp(&other.page)
+    }
+}
+
+/// Implementation of [`Display`] for [`Page`].
+impl<E> Display for Page<E>
+where
+    E: Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "Page {{ items: {:?}, page: {}, size: {}, total: {}, pages: {}, previous_page: {:?}, next_page: {:?} }}",
+            self.items, self.page, self.size, self.total, self.pages, self.previous_page, self.next_page
+        )
+    }
+}
+
+/// Implementation of [`IntoIterator`] for [`Page`].
+impl<E> IntoIterator for Page<E> {
+    type Item = E;
+    type IntoIter = alloc::vec::IntoIter<Self::Item>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.into_iter()
+    }
+}
+
+/// Implementation of [`FromIterator`] for [`Page`].
+///
+/// This always yields a single [`Page`] holding every collected item, at ***page*** 0 with ***size*** and ***total*** equal to the number of items.
+impl<E> FromIterator<E> for Page<E> {
+    fn from_iter<I: IntoIterator<Item = E>>(iter: I) -> Self {
+        let items: Vec<E> = iter.into_iter().collect();
+        let total: usize = items.len();
+
+        Page {
+            items,
+            page: 0,
+            size: total,
+            total,
+            pages: 1,
+            previous_page: None,
+            next_page: None,
+        }
+    }
+}
+
+/// Implementation of [`From`] of [`Page`] for [`Vec`], moving the ***items*** out of the [`Page`] and dropping the pagination fields.
+impl<E> From<Page<E>> for Vec<E> {
+    fn from(page: Page<E>) -> Self {
+        page.items
+    }
+}
+
+/// Implementation of [`DeepSizeOf`] for [`Page`] if the feature `deepsize` is enabled.
+#[cfg(feature = "deepsize")]
+impl<E> DeepSizeOf for Page<E>
+where
+    E: DeepSizeOf,
+{
+    fn deep_size_of_children(&self, context: &mut DeepSizeContext) -> usize {
+        self.items.deep_size_of_children(context)
+    }
+}
+
+/// Implementation of [`Serialize`] for [`Page`] if the feature `serde` is enabled.
+///
+/// If the feature `serde-skip-null-links` is enabled, ***previous_page*** and ***next_page*** are omitted when they are [`None`]. Missing links are still deserialized as [`None`].
+#[cfg(feature = "serde")]
+impl<E> Serialize for Page<E>
+where
+    E: Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        #[derive(Serialize)]
+        struct PageModel<'a, E>
+        where
+            E: Serialize,
+        {
+            items: &'a Vec<E>,
+            page: usize,
+            size: usize,
+            total: usize,
+            pages: usize,
+            #[cfg_attr(feature = "serde-camel-case", serde(rename = "previousPage"))]
+            #[cfg_attr(
+                feature = "serde-skip-null-links",
+                serde(skip_serializing_if = "Option::is_none")
+            )]
+            previous_page: Option<usize>,
+            #[cfg_attr(feature = "serde-camel-case", serde(rename = "nextPage"))]
+            #[cfg_attr(
+                feature = "serde-skip-null-links",
+                serde(skip_serializing_if = "Option::is_none")
+            )]
+            next_page: Option<usize>,
+        }
+
+        let page_model: PageModel<E> = PageModel {
+            items: &self.items,
+            page: self.page,
+            size: self.size,
+            total: self.total,
+            pages: self.pages,
+            previous_page: self.previous_page,
+            next_page: self.next_page,
+        };
+
+        page_model.serialize(serializer)
+    }
+}
+
+/// Model to encode a [`Page`] with [`bincode`] in [`Page::to_bytes`], with every field in the order expected by the [`Deserialize`] implementation of [`Page`], whatever the serde features enabled.
+#[cfg(feature = "bincode")]
+#[derive(Serialize)]
+struct BincodePage<'a, E>
+where
+    E: Serialize,
+{
+    items: &'a Vec<E>,
+    page: usize,
+    size: usize,
+    total: usize,
+    pages: usize,
+    previous_page: Option<usize>,
+    next_page: Option<usize>,
+}
+
+/// Implementation of [`Deserialize`] for [`Page`] if the feature `serde` is enabled.
+///
+/// The fields are verified after deserialization, whatever the type of the items. Items can borrow from the input: `Page<&str>` deserializes without allocating, but fails on strings with escape sequences, while `Page<Cow<str>>` always allocates owned strings because of the [`Deserialize`] implementation of [`Cow`](std::borrow::Cow) in serde. To borrow when possible and allocate only for escaped strings, wrap the [`Cow`](std::borrow::Cow) in a newtype with `#[serde(borrow)]`:
+///
+///```rust,no_run
+/// # #[cfg(feature = "serde")]
+/// # fn main() {
+/// use page_hunter::*;
+/// use serde::Deserialize;
+/// use std::borrow::Cow;
+///
+/// #[derive(Deserialize)]
+/// struct Name<'a>(#[serde(borrow)] Cow<'a, str>);
+///
+/// let json: &str = r#"{"items":["alice","bob"],"page":0,"size":2,"total":2,"pages":1,"previous_page":null,"next_page":null}"#;
+///
+/// let page: Page<Name> = serde_json::from_str(json).unwrap_or_else(|error| {
+///     panic!("Error deserializing page model: {:?}", error);
+/// });
+/// # }
+/// # #[cfg(not(feature = "serde"))]
+/// # fn main() {}
+/// ````
+#[cfg(feature = "serde")]
+impl<'de, E> DeDeserialize<'de> for Page<E>
 where
     E: Deserialize<'de>,
 {
@@ -355,7 +1849,15 @@ where
             size: usize,
             total: usize,
             pages: usize,
+            #[cfg_attr(
+                feature = "serde-camel-case",
+                serde(rename = "previousPage", alias = "previous_page")
+            )]
             previous_page: Option<usize>,
+            #[cfg_attr(
+                feature = "serde-camel-case",
+                serde(rename = "nextPage", alias = "next_page")
+            )]
             next_page: Option<usize>,
         }
 
@@ -377,6 +1879,95 @@ where
     }
 }
 
+/// Deserialize a [`Page`] from either a paginated object or a bare array of items, which is useful to consume APIs that return a plain array when the results are small. Only available when the `serde` feature is enabled.
+///
+/// ### Arguments:
+/// - **deserializer**: A [`Deserializer`](serde::Deserializer) of a self-describing format such as JSON.
+///
+/// ### Returns:
+/// A [`Result`] with a [`Page`] of items `E` if successful, otherwise the error of the deserializer is returned.
+///
+/// A paginated object is deserialized and verified as any [`Page`]. A bare array is deserialized into a single [`Page`] holding all the items, like [`Page::from_iter`].
+///
+/// ### Example:
+///```rust,no_run
+/// # #[cfg(feature = "serde")]
+/// # fn main() {
+/// use page_hunter::*;
+/// use serde::Deserialize;
+///
+/// #[derive(Deserialize)]
+/// struct SearchResponse {
+///     #[serde(deserialize_with = "deserialize_page_or_array")]
+///     results: Page<u32>,
+/// }
+///
+/// let response: SearchResponse = serde_json::from_str(r#"{"results":[1,2,3]}"#)
+///     .unwrap_or_else(|error| {
+///         panic!("Error deserializing response: {:?}", error);
+///     });
+/// # }
+/// # #[cfg(not(feature = "serde"))]
+/// # fn main() {}
+/// ````
+#[cfg(feature = "serde")]
+pub fn deserialize_page_or_array<'de, D, E>(deserializer: D) -> Result<Page<E>, D::Error>
+where
+    D: DeDeserializer<'de>,
+    E: Deserialize<'de>,
+{
+    struct PageOrArrayVisitor<E>(PhantomData<E>);
+
+    impl<'de, E> Visitor<'de> for PageOrArrayVisitor<E>
+    where
+        E: Deserialize<'de>,
+    {
+        type Value = Page<E>;
+
+        fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+            formatter.write_str("a page object or an array of items")
+        }
+
+        fn visit_seq<A>(self, seq: A) -> Result<Page<E>, A::Error>
+        where
+            A: SeqAccess<'de>,
+        {
+            let items: Vec<E> = DeDeserialize::deserialize(SeqAccessDeserializer::new(seq))?;
+
+            Ok(Page::from_iter(items))
+        }
+
+        fn visit_map<A>(self, map: A) -> Result<Page<E>, A::Error>
+        where
+            A: MapAccess<'de>,
+        {
+            DeDeserialize::deserialize(MapAccessDeserializer::new(map))
+        }
+    }
+
+    deserializer.deserialize_any(PageOrArrayVisitor(PhantomData))
+}
+
+/// Implementation of [`Validate`] for [`Page`] if the feature `validator` is enabled.
+///
+/// The page fields are verified with the same rules used by [`Page::new`]. Any violation is reported under the `__all__` key of [`ValidationErrors`] with the code `page`, so [`Page`] fields can be nested in structs deriving [`Validate`].
+#[cfg(feature = "validator")]
+impl<E> Validate for Page<E> {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        match self.verify_fields() {
+            Ok(()) => Ok(()),
+            Err(error) => {
+                let mut errors: ValidationErrors = ValidationErrors::new();
+                errors.add(
+                    "__all__",
+                    ValidationError::new("page").with_message(error.to_string().into()),
+                );
+                Err(errors)
+            }
+        }
+    }
+}
+
 /// Implementation of [`ToSchema`] for [`Page`] if the feature `utoipa` is enabled.
 #[cfg(feature = "utoipa")]
 impl<'s, E> ToSchema<'s> for Page<E>
@@ -387,12 +1978,9 @@ where
         (
             "Page",
             ObjectBuilder::new()
-				.description(Some("Model to represent paginated items."))
-				.property(
-					"items", 
-					E::schema().1,
-				)
-				.required("items")
+                .description(Some("Model to represent paginated items."))
+                .property("items", ArrayBuilder::new().items(E::schema().1))
+                .required("items")
                 .property(
                     "page",
                     ObjectBuilder::new()
@@ -402,61 +1990,537 @@ where
                         .schema_type(SchemaType::Integer)
                         .format(Some(SchemaFormat::KnownFormat(KnownFormat::Int64)))
                         .minimum(Some(0.0))
+                        .example(Some(serde_json::Value::from(1))),
                 )
                 .required("page")
-				.property(
-					"size",
-					ObjectBuilder::new()
-						.description(Some(
-							"The maximum number of elements per Page. items length must be equal to size value for all pages except the last page, when items length could be less than or equal to size.",
-						))
-						.schema_type(SchemaType::Integer)
-						.format(Some(SchemaFormat::KnownFormat(KnownFormat::Int64)))
-						.minimum(Some(0.0))
-				)
-				.required("size")
-				.property(
-					"total",
-					ObjectBuilder::new()
-						.description(Some(
-							"The total number of records used for pagination.",
-						))
-						.schema_type(SchemaType::Integer)
-						.format(Some(SchemaFormat::KnownFormat(KnownFormat::Int64)))
-						.minimum(Some(0.0))
-				)
-				.required("total")
-				.property(
-					"pages",
-					ObjectBuilder::new()
-						.description(Some(
-							"Represents the total number of pages required for paginate the items.",
-						))
-						.schema_type(SchemaType::Integer)
-						.format(Some(SchemaFormat::KnownFormat(KnownFormat::Int64)))
-						.minimum(Some(1.0))
-				)
-				.required("pages")
-				.property(
-					"previous_page",
-					ObjectBuilder::new()
-						.description(Some(
-							"Represents the previous page index in a Page. If there is no previous page, it will be None.",
-						))
-						.schema_type(SchemaType::Integer)
-						.format(Some(SchemaFormat::KnownFormat(KnownFormat::Int64))
-				)
-				.property(
-					"next_page",
-					ObjectBuilder::new()
-						.description(Some(
-							"Represents the next page index in a Page. If there is no next page, it will be None.",
-						))
-						.schema_type(SchemaType::Integer)
-						.format(Some(SchemaFormat::KnownFormat(KnownFormat::Int64)))
-				)
-       		).into()
-		)
+                .property(
+                    "size",
+                    ObjectBuilder::new()
+                        .description(Some(
+                            "The maximum number of elements per Page. items length must be equal to size value for all pages except the last page, when items length could be less than or equal to size.",
+                        ))
+                        .schema_type(SchemaType::Integer)
+                        .format(Some(SchemaFormat::KnownFormat(KnownFormat::Int64)))
+                        .minimum(Some(0.0))
+                        .example(Some(serde_json::Value::from(10))),
+                )
+                .required("size")
+                .property(
+                    "total",
+                    ObjectBuilder::new()
+                        .description(Some("The total number of records used for pagination."))
+                        .schema_type(SchemaType::Integer)
+                        .format(Some(SchemaFormat::KnownFormat(KnownFormat::Int64)))
+                        .minimum(Some(0.0))
+                        .example(Some(serde_json::Value::from(25))),
+                )
+                .required("total")
+                .property(
+                    "pages",
+                    ObjectBuilder::new()
+                        .description(Some(
+                            "Represents the total number of pages required for paginate the items.",
+                        ))
+                        .schema_type(SchemaType::Integer)
+                        .format(Some(SchemaFormat::KnownFormat(KnownFormat::Int64)))
+                        .minimum(Some(1.0))
+                        .example(Some(serde_json::Value::from(3))),
+                )
+                .required("pages")
+                .property(
+                    PREVIOUS_PAGE_FIELD,
+                    ObjectBuilder::new()
+                        .description(Some(
+                            "Represents the previous page index in a Page. If there is no previous page, it will be None.",
+                        ))
+                        .schema_type(SchemaType::Integer)
+                        .format(Some(SchemaFormat::KnownFormat(KnownFormat::Int64)))
+                        .nullable(true)
+                        .example(Some(serde_json::Value::from(0))),
+                )
+                .property(
+                    NEXT_PAGE_FIELD,
+                    ObjectBuilder::new()
+                        .description(Some(
+                            "Represents the next page index in a Page. If there is no next page, it will be None.",
+                        ))
+                        .schema_type(SchemaType::Integer)
+                        .format(Some(SchemaFormat::KnownFormat(KnownFormat::Int64)))
+                        .nullable(true)
+                        .example(Some(serde_json::Value::from(2))),
+                )
+                .into(),
+        )
+    }
+}
+
+/// Build the utoipa 5.x schema of an integer field of a [`Page`] with a minimum and an example value, which is nullable for the page-index fields that may be missing.
+#[cfg(feature = "utoipa-5")]
+fn integer_utoipa5_schema(
+    description: &str,
+    minimum: Option<u64>,
+    example: u64,
+    nullable: bool,
+) -> ObjectBuilder5 {
+    let builder: ObjectBuilder5 = ObjectBuilder5::new()
+        .description(Some(description))
+        .format(Some(SchemaFormat5::KnownFormat(KnownFormat5::Int64)))
+        .minimum(minimum)
+        .examples([example]);
+
+    match nullable {
+        true => builder.schema_type(SchemaType5::from_iter([Type5::Integer, Type5::Null])),
+        false => builder.schema_type(Type5::Integer),
+    }
+}
+
+/// Implementation of [`PartialSchema`](utoipa5::PartialSchema) for [`Page`] if the feature `utoipa-5` is enabled.
+#[cfg(feature = "utoipa-5")]
+impl<E> PartialSchema5 for Page<E>
+where
+    E: ToSchema5,
+{
+    fn schema() -> RefOr5<Schema5> {
+        ObjectBuilder5::new()
+            .description(Some("Model to represent paginated items."))
+            .property("items", ArrayBuilder5::new().items(E::schema()))
+            .required("items")
+            .property(
+                "page",
+                integer_utoipa5_schema(
+                    "The page index in a Page. It starts from 0 to pages - 1.",
+                    Some(0),
+                    1,
+                    false,
+                ),
+            )
+            .required("page")
+            .property(
+                "size",
+                integer_utoipa5_schema(
+                    "The maximum number of elements per Page. items length must be equal to size value for all pages except the last page, when items length could be less than or equal to size.",
+                    Some(0),
+                    10,
+                    false,
+                ),
+            )
+            .required("size")
+            .property(
+                "total",
+                integer_utoipa5_schema(
+                    "The total number of records used for pagination.",
+                    Some(0),
+                    25,
+                    false,
+                ),
+            )
+            .required("total")
+            .property(
+                "pages",
+                integer_utoipa5_schema(
+                    "Represents the total number of pages required for paginate the items.",
+                    Some(1),
+                    3,
+                    false,
+                ),
+            )
+            .required("pages")
+            .property(
+                PREVIOUS_PAGE_FIELD,
+                integer_utoipa5_schema(
+                    "Represents the previous page index in a Page. If there is no previous page, it will be None.",
+                    None,
+                    0,
+                    true,
+                ),
+            )
+            .property(
+                NEXT_PAGE_FIELD,
+                integer_utoipa5_schema(
+                    "Represents the next page index in a Page. If there is no next page, it will be None.",
+                    None,
+                    2,
+                    true,
+                ),
+            )
+            .into()
+    }
+}
+
+/// Implementation of [`ToSchema`](utoipa5::ToSchema) for [`Page`] if the feature `utoipa-5` is enabled.
+#[cfg(feature = "utoipa-5")]
+impl<E> ToSchema5 for Page<E>
+where
+    E: ToSchema5,
+{
+    fn name() -> alloc::borrow::Cow<'static, str> {
+        alloc::borrow::Cow::Borrowed("Page")
+    }
+
+    fn schemas(schemas: &mut Vec<(String, RefOr5<Schema5>)>) {
+        schemas.push((E::name().into_owned(), E::schema()));
+        E::schemas(schemas);
+    }
+}
+
+/// Build the [`JsonSchema`] of an integer field of a [`Page`] with a minimum value, which is nullable for the page-index fields that may be missing.
+#[cfg(feature = "schemars")]
+fn integer_json_schema(description: &str, minimum: f64, nullable: bool) -> SchemarsSchema {
+    SchemarsSchema::Object(SchemaObject {
+        metadata: Some(Box::new(Metadata {
+            description: Some(description.to_string()),
+            ..Default::default()
+        })),
+        instance_type: Some(match nullable {
+            true => SingleOrVec::Vec(vec![InstanceType::Integer, InstanceType::Null]),
+            false => SingleOrVec::Single(Box::new(InstanceType::Integer)),
+        }),
+        format: Some(String::from("uint")),
+        number: Some(Box::new(NumberValidation {
+            minimum: Some(minimum),
+            ..Default::default()
+        })),
+        ..Default::default()
+    })
+}
+
+/// Implementation of [`JsonSchema`] for [`Page`] if the feature `schemars` is enabled.
+#[cfg(feature = "schemars")]
+impl<E> JsonSchema for Page<E>
+where
+    E: JsonSchema,
+{
+    fn schema_name() -> String {
+        format!("Page_of_{}", E::schema_name())
+    }
+
+    fn json_schema(gen: &mut SchemaGenerator) -> SchemarsSchema {
+        let mut object: ObjectValidation = ObjectValidation::default();
+
+        object.properties.insert(
+            String::from("items"),
+            SchemarsSchema::Object(SchemaObject {
+                metadata: Some(Box::new(Metadata {
+                    description: Some(String::from(
+                        "Represents the items in a Page as a Vec of E.",
+                    )),
+                    ..Default::default()
+                })),
+                instance_type: Some(SingleOrVec::Single(Box::new(InstanceType::Array))),
+                array: Some(Box::new(ArrayValidation {
+                    items: Some(SingleOrVec::Single(Box::new(gen.subschema_for::<E>()))),
+                    ..Default::default()
+                })),
+                ..Default::default()
+            }),
+        );
+        object.properties.insert(
+            String::from("page"),
+            integer_json_schema(
+                "The page index in a Page. It starts from 0 to pages - 1.",
+                0.0,
+                false,
+            ),
+        );
+        object.properties.insert(
+            String::from("size"),
+            integer_json_schema("The maximum number of elements per Page.", 0.0, false),
+        );
+        object.properties.insert(
+            String::from("total"),
+            integer_json_schema(
+                "The total number of records used for pagination.",
+                0.0,
+                false,
+            ),
+        );
+        object.properties.insert(
+            String::from("pages"),
+            integer_json_schema(
+                "Represents the total number of pages required for paginate the items.",
+                1.0,
+                false,
+            ),
+        );
+        object.properties.insert(
+            String::from(PREVIOUS_PAGE_FIELD),
+            integer_json_schema(
+                "Represents the previous page index in a Page. If there is no previous page, it will be null.",
+                0.0,
+                true,
+            ),
+        );
+        object.properties.insert(
+            String::from(NEXT_PAGE_FIELD),
+            integer_json_schema(
+                "Represents the next page index in a Page. If there is no next page, it will be null.",
+                0.0,
+                true,
+            ),
+        );
+        object.required.extend(
+            ["items", "page", "size", "total", "pages"]
+                .iter()
+                .map(|field| field.to_string()),
+        );
+
+        SchemarsSchema::Object(SchemaObject {
+            metadata: Some(Box::new(Metadata {
+                description: Some(String::from("Model to represent paginated items.")),
+                ..Default::default()
+            })),
+            instance_type: Some(SingleOrVec::Single(Box::new(InstanceType::Object))),
+            object: Some(Box::new(object)),
+            ..Default::default()
+        })
+    }
+}
+
+/// Model to represent the metadata of a [`Page`] without its items, returned by [`Page::metadata`].
+///
+/// #### Fields:
+/// - **page**: Represents the page index in a [`Page`]. It starts from 0 to ***pages*** - 1.
+/// - **size**: Represents the maximum number of elements per [`Page`].
+/// - **total**: Represents the total number of records used for pagination.
+/// - **pages**: Represents the total number of pages required for paginate the items.
+/// - **previous_page**: Represents the previous page index in a [`Page`]. If there is no previous page, it will be [`None`].
+/// - **next_page**: Represents the next page index in a [`Page`]. If there is no next page, it will be [`None`].
+///
+/// It implements [`Serialize`] and [`Deserialize`] if the feature `serde` is enabled, with the same field names as a serialized [`Page`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PageMetadata {
+    page: usize,
+    size: usize,
+    total: usize,
+    pages: usize,
+    #[cfg_attr(
+        feature = "serde-camel-case",
+        serde(rename = "previousPage", alias = "previous_page")
+    )]
+    previous_page: Option<usize>,
+    #[cfg_attr(
+        feature = "serde-camel-case",
+        serde(rename = "nextPage", alias = "next_page")
+    )]
+    next_page: Option<usize>,
+}
+
+impl PageMetadata {
+    /// Get ***page***
+    pub fn get_page(&self) -> usize {
+        self.page
+    }
+
+    /// Get ***size***
+    pub fn get_size(&self) -> usize {
+        self.size
+    }
+
+    /// Get ***total***
+    pub fn get_total(&self) -> usize {
+        self.total
+    }
+
+    /// Get ***pages***
+    pub fn get_pages(&self) -> usize {
+        self.pages
+    }
+
+    /// Get ***previous_page***
+    pub fn get_previous_page(&self) -> Option<usize> {
+        self.previous_page
+    }
+
+    /// Get ***next_page***
+    pub fn get_next_page(&self) -> Option<usize> {
+        self.next_page
+    }
+
+    /// Attach a [`Vec`] of items to the metadata, building a new [`Page`].
+    ///
+    /// ### Arguments:
+    /// - **items**: A [`Vec`] of items `T`.
+    ///
+    /// ### Returns:
+    /// A [`PaginationResult`] with a [`Page`] of `T` if successful, otherwise a [`PaginationError`] is returned.
+    ///
+    /// The [`Page`] is built as with [`Page::from_parts`] from the stored ***page***, ***size*** and ***total***, so the number of items is verified again. The metadata of an empty [`Page`] beyond the last page can only be attached to no items.
+    ///
+    /// ### Example:
+    ///```rust,no_run
+    /// use page_hunter::*;
+    ///
+    /// let page: Page<u32> = Page::new(&vec![3, 4], 1, 2, 5).unwrap_or_else(|error| {
+    ///     panic!("Error creating page model: {:?}", error);
+    /// });
+    ///
+    /// let (items, metadata): (Vec<u32>, PageMetadata) = page.split_off_items();
+    /// let labels: Vec<String> = items.iter().map(|item| format!("#{}", item)).collect();
+    ///
+    /// let labeled_page: Page<String> = metadata.attach(labels).unwrap_or_else(|error| {
+    ///     panic!("Error attaching items: {:?}", error);
+    /// });
+    /// ````
+    pub fn attach<T>(self, items: Vec<T>) -> PaginationResult<Page<T>> {
+        Page::rebuild(items, self.page, self.size, self.total)
+    }
+}
+
+/// Model to represent the request of a [`Page`], with the page index and size to fetch.
+///
+/// #### Fields:
+/// - **page**: Represents the requested page index. It starts from 0.
+/// - **size**: Represents the requested maximum number of elements per page.
+///
+/// It is built with [`PageRequest::new`] or with a [`PageRequestBuilder`], which centralizes the defaults and the limits of the optional page parameters received by a web handler.
+///
+/// ### Example:
+/// ```rust,no_run
+/// use page_hunter::*;
+///
+/// let page: Option<usize> = Some(2);
+/// let size: Option<usize> = None;
+///
+/// let request: PageRequest = PageRequest::builder()
+///     .page(page)
+///     .size(size)
+///     .default_size(50)
+///     .build()
+///     .validated(100)
+///     .unwrap_or_else(|error| {
+///         panic!("Error validating page request: {:?}", error);
+///     });
+///
+/// let records: Vec<u32> = (1..=200).collect();
+/// let page: Page<u32> =
+///     paginate_records(&records, request.get_page(), request.get_size()).unwrap_or_else(|error| {
+///         panic!("Error paginating records: {:?}", error);
+///     });
+/// ````
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PageRequest {
+    page: usize,
+    size: usize,
+}
+
+impl PageRequest {
+    /// Size used by a [`PageRequestBuilder`] when neither the size nor a default size is given.
+    pub const DEFAULT_SIZE: usize = 10;
+
+    /// Get ***page***
+    pub fn get_page(&self) -> usize {
+        self.page
+    }
+
+    /// Get ***size***
+    pub fn get_size(&self) -> usize {
+        self.size
+    }
+
+    /// Create a new [`PageRequest`] instance.
+    ///
+    /// ### Arguments:
+    /// - **page**: The requested page index.
+    /// - **size**: The requested number of records per page.
+    ///
+    /// ### Returns:
+    /// A [`PageRequest`] with the given fields, which are not validated.
+    pub fn new(page: usize, size: usize) -> PageRequest {
+        PageRequest { page, size }
+    }
+
+    /// Create a [`PageRequestBuilder`] to build a [`PageRequest`] from optional parameters.
+    pub fn builder() -> PageRequestBuilder {
+        PageRequestBuilder::default()
+    }
+
+    /// Validate the size of the [`PageRequest`] against a maximum.
+    ///
+    /// ### Arguments:
+    /// - **max_size**: The maximum number of records per page accepted.
+    ///
+    /// ### Returns:
+    /// A [`PaginationResult`] with the [`PageRequest`] if ***size*** is between 1 and **max_size**, otherwise a [`PaginationError`] of kind [`ErrorKind::FieldValueError`].
+    pub fn validated(self, max_size: usize) -> PaginationResult<PageRequest> {
+        if self.size.eq(&0) {
+            return Err(PaginationError::from(ErrorKind::FieldValueError(
+                String::from("Page size must be greater than zero"),
+            )));
+        }
+
+        if self.size.gt(&max_size) {
+            return Err(PaginationError::from(ErrorKind::FieldValueError(format!(
+                "Page size '{}' exceeds the maximum size '{}'",
+                self.size, max_size
+            ))));
+        }
+
+        Ok(self)
+    }
+}
+
+impl From<(usize, usize)> for PageRequest {
+    fn from((page, size): (usize, usize)) -> PageRequest {
+        PageRequest::new(page, size)
+    }
+}
+
+impl From<PageRequest> for (usize, usize) {
+    fn from(request: PageRequest) -> (usize, usize) {
+        (request.page, request.size)
+    }
+}
+
+/// Builder of a [`PageRequest`] from optional parameters, returned by [`PageRequest::builder`].
+///
+/// A missing page defaults to 0 and a missing size defaults to the default size, which is [`PageRequest::DEFAULT_SIZE`] unless it is changed with [`PageRequestBuilder::default_size`]. If a maximum size is set with [`PageRequestBuilder::max_size`], a larger size is clamped to it.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct PageRequestBuilder {
+    page: Option<usize>,
+    size: Option<usize>,
+    default_size: Option<usize>,
+    max_size: Option<usize>,
+}
+
+impl PageRequestBuilder {
+    /// Set the requested page index, where [`None`] keeps the default page 0.
+    pub fn page(mut self, page: impl Into<Option<usize>>) -> PageRequestBuilder {
+        self.page = page.into();
+        self
+    }
+
+    /// Set the requested size, where [`None`] keeps the default size.
+    pub fn size(mut self, size: impl Into<Option<usize>>) -> PageRequestBuilder {
+        self.size = size.into();
+        self
+    }
+
+    /// Set the size used when no size is requested.
+    pub fn default_size(mut self, default_size: usize) -> PageRequestBuilder {
+        self.default_size = Some(default_size);
+        self
+    }
+
+    /// Set the maximum size, to which a larger requested size is clamped.
+    pub fn max_size(mut self, max_size: usize) -> PageRequestBuilder {
+        self.max_size = Some(max_size);
+        self
+    }
+
+    /// Build the [`PageRequest`] with the defaults and the clamping applied.
+    pub fn build(self) -> PageRequest {
+        let size: usize = self
+            .size
+            .unwrap_or(self.default_size.unwrap_or(PageRequest::DEFAULT_SIZE));
+
+        PageRequest {
+            page: self.page.unwrap_or(0),
+            size: match self.max_size {
+                Some(max_size) => size.min(max_size),
+                None => size,
+            },
+        }
     }
 }
 
@@ -504,6 +2568,324 @@ impl<E> Book<E> {
             sheets: sheets.to_owned(),
         }
     }
+
+    /// Create a new [`Book`] instance taking ownership of the ***sheets*** and verifying them with [`Book::verify`].
+    ///
+    /// ### Arguments:
+    /// - **sheets**: A [`Vec`] of [`Page`].
+    ///
+    /// ### Returns:
+    /// A [`PaginationResult`] containing a [`Book`] if the ***sheets*** share the same ***size*** and ***total***, their ***page*** indices are contiguous from 0 and their number is equal to ***pages***, otherwise a [`PaginationError`] is returned.
+    ///
+    /// ### Example:
+    /// ```rust,no_run
+    /// use page_hunter::*;
+    ///
+    /// let sheets: Vec<Page<u32>> = vec![
+    ///     Page::new(&vec![1, 2], 0, 2, 3).unwrap_or_else(|error| {
+    ///         panic!("Error creating page model: {:?}", error);
+    ///     }),
+    ///     Page::new(&vec![3], 1, 2, 3).unwrap_or_else(|error| {
+    ///         panic!("Error creating page model: {:?}", error);
+    ///     }),
+    /// ];
+    ///
+    /// let book_result: PaginationResult<Book<u32>> = Book::try_new(sheets);
+    /// ```
+    pub fn try_new(sheets: Vec<Page<E>>) -> PaginationResult<Book<E>> {
+        let book: Book<E> = Book { sheets };
+        book.verify()?;
+
+        Ok(book)
+    }
+
+    /// Find the first record matching a predicate across all the ***sheets*** of the [`Book`].
+    ///
+    /// ### Arguments:
+    /// - **f**: A predicate applied to each record `E`, where the ***sheets*** are iterated in order.
+    ///
+    /// ### Returns:
+    /// [`Some`] with a tuple of the page index where the record was found and the absolute index of the record across all pages, otherwise [`None`].
+    ///
+    /// ### Example:
+    /// ```rust,no_run
+    /// use page_hunter::*;
+    ///
+    /// let book: Book<u32> = bind_records(&vec![1, 2, 3, 4, 5], 2).unwrap_or_else(|error| {
+    ///     panic!("Error binding records: {:?}", error);
+    /// });
+    ///
+    /// let position: Option<(usize, usize)> = book.find_record(|record| *record == 4);
+    /// ```
+    pub fn find_record<F>(&self, mut f: F) -> Option<(usize, usize)>
+    where
+        F: FnMut(&E) -> bool,
+    {
+        self.get_sheets().iter().find_map(|sheet| {
+            sheet.get_items().iter().position(&mut f).map(|position| {
+                (
+                    sheet.get_page(),
+                    sheet
+                        .get_page()
+                        .saturating_mul(sheet.get_size())
+                        .saturating_add(position),
+                )
+            })
+        })
+    }
+
+    /// Remove the records whose key already appeared on an earlier sheet of the [`Book`], and rebuild the ***sheets***.
+    ///
+    /// ### Arguments:
+    /// - **key**: A closure that extracts the key `K` of each record `E`, where `K` must implement [`PartialEq`].
+    ///
+    /// ### Returns:
+    /// An empty [`PaginationResult`] if successful, otherwise a [`PaginationError`] is returned and the [`Book`] is left unchanged.
+    ///
+    /// This is a best-effort cleanup for overlapping sheets, for example when concurrent inserts shift records between pages that were read separately. Duplicates within the same sheet are kept, and a [`Book`] without duplicates is left unchanged. Otherwise, the remaining records are rebound starting from the page index of the first sheet, keeping its ***size***, so the rebuilt sheets can be fewer than the original ones. The ***total*** of the rebuilt sheets counts the records up to the last of them, which is the original ***total*** reduced by the number of removed records when the [`Book`] reaches the last page. When the [`Book`] only holds some of the pages, the records beyond its last sheet are not known, so the last rebuilt sheet becomes the last page.
+    ///
+    /// ### Example:
+    /// ```rust,no_run
+    /// use page_hunter::*;
+    ///
+    /// let mut book: Book<u32> = Book::new(&vec![
+    ///     Page::new(&vec![1, 2], 0, 2, 6).unwrap_or_else(|error| {
+    ///         panic!("Error creating page model: {:?}", error);
+    ///     }),
+    ///     Page::new(&vec![2, 3], 1, 2, 6).unwrap_or_else(|error| {
+    ///         panic!("Error creating page model: {:?}", error);
+    ///     }),
+    /// ]);
+    ///
+    /// book.dedup_by_key(|record| *record).unwrap_or_else(|error| {
+    ///     panic!("Error removing duplicated records: {:?}", error);
+    /// });
+    ///
+    /// assert_eq!(book.get_sheets()[1].get_items(), &vec![3]);
+    /// ```
+    pub fn dedup_by_key<K, F>(&mut self, mut key: F) -> PaginationResult<()>
+    where
+        E: Clone,
+        K: PartialEq,
+        F: FnMut(&E) -> K,
+    {
+        let (first_page, size): (usize, usize) = match self.get_sheets().first() {
+            Some(sheet) => (sheet.get_page(), sheet.get_size()),
+            None => return Ok(()),
+        };
+
+        let mut seen_keys: Vec<K> = Vec::new();
+        let mut records: Vec<E> = Vec::new();
+        let mut removed: usize = 0;
+
+        for sheet in self.get_sheets() {
+            let mut sheet_keys: Vec<K> = Vec::new();
+
+            for record in sheet.get_items() {
+                let record_key: K = key(record);
+
+                match seen_keys.contains(&record_key) {
+                    true => removed += 1,
+                    false => {
+                        records.push(record.clone());
+                        sheet_keys.push(record_key);
+                    }
+                }
+            }
+
+            seen_keys.extend(sheet_keys);
+        }
+
+        if removed.eq(&0) {
+            return Ok(());
+        }
+
+        // The rebuilt sheets hold every record up to the last of them, so their total is consistent whether or not the book reaches the last page.
+        let total: usize = first_page
+            .saturating_mul(size)
+            .saturating_add(records.len());
+
+        let chunk_size: usize = match size.eq(&0) {
+            true => records.len().max(1),
+            false => size,
+        };
+
+        let mut chunks: Vec<Vec<E>> = records
+            .chunks(chunk_size)
+            .map(|chunk| chunk.to_vec())
+            .collect();
+        if chunks.is_empty() {
+            chunks.push(Vec::new());
+        }
+
+        self.sheets = chunks
+            .into_iter()
+            .enumerate()
+            .map(|(index, items)| Page::from_parts(items, first_page + index, size, total))
+            .collect::<PaginationResult<Vec<Page<E>>>>()?;
+
+        Ok(())
+    }
+
+    /// Paginate the ***sheets*** of the [`Book`] into a [`Page`] of sheets, which is useful to navigate very large books.
+    ///
+    /// ### Arguments:
+    /// - **page**: The page index.
+    /// - **size**: The number of sheets per page.
+    ///
+    /// ### Returns:
+    /// A [`PaginationResult`] containing a [`Page`] whose items are the sheets of the [`Book`], where `E` must implement [`Clone`].
+    ///
+    /// ### Example:
+    /// ```rust,no_run
+    /// use page_hunter::*;
+    ///
+    /// let book: Book<u32> = bind_records(&(1..=20).collect::<Vec<u32>>(), 2).unwrap_or_else(|error| {
+    ///     panic!("Error binding records: {:?}", error);
+    /// });
+    ///
+    /// let sheets_page_result: PaginationResult<Page<Page<u32>>> = book.paginate_sheets(1, 3);
+    /// ```
+    pub fn paginate_sheets(&self, page: usize, size: usize) -> PaginationResult<Page<Page<E>>>
+    where
+        E: Clone,
+    {
+        paginate_records(self.get_sheets(), page, size)
+    }
+
+    /// Verify that the ***sheets*** of the [`Book`] are consistent, which is useful for books created with [`Book::new`] from sheets of unknown origin.
+    ///
+    /// ### Arguments:
+    /// *No arguments*
+    ///
+    /// ### Returns:
+    /// A [`PaginationResult`] with `()` if successful, otherwise a [`PaginationError`] is returned.
+    ///
+    /// The following conditions are checked:
+    /// - all the ***sheets*** must have the same ***size*** and ***total***.
+    /// - the ***page*** of each sheet must be equal to its position in the [`Book`], so the page indices are contiguous from 0.
+    /// - the number of ***sheets*** must be equal to ***pages***.
+    ///
+    /// A [`Book`] without ***sheets*** is considered valid.
+    ///
+    /// ### Example:
+    /// ```rust,no_run
+    /// use page_hunter::*;
+    ///
+    /// let book: Book<u32> = Book::new(&vec![
+    ///     Page::new(&vec![1, 2], 0, 2, 3).unwrap_or_else(|error| {
+    ///         panic!("Error creating page model: {:?}", error);
+    ///     }),
+    ///     Page::new(&vec![3], 1, 2, 3).unwrap_or_else(|error| {
+    ///         panic!("Error creating page model: {:?}", error);
+    ///     }),
+    /// ]);
+    ///
+    /// let verify_result: PaginationResult<()> = book.verify();
+    /// ```
+    pub fn verify(&self) -> PaginationResult<()> {
+        let (size, total, pages): (usize, usize, usize) = match self.get_sheets().first() {
+            Some(sheet) => (sheet.get_size(), sheet.get_total(), sheet.get_pages()),
+            None => return Ok(()),
+        };
+
+        for (index, sheet) in self.get_sheets().iter().enumerate() {
+            if sheet.get_size().ne(&size) {
+                return Err(PaginationError::from(ErrorKind::FieldValueError(format!(
+                    "Sheet '{}' size '{}' is not equal to book size '{}'",
+                    index,
+                    sheet.get_size(),
+                    size,
+                ))));
+            }
+
+            if sheet.get_total().ne(&total) {
+                return Err(PaginationError::from(ErrorKind::FieldValueError(format!(
+                    "Sheet '{}' total '{}' is not equal to book total '{}'",
+                    index,
+                    sheet.get_total(),
+                    total,
+                ))));
+            }
+
+            if sheet.get_page().ne(&index) {
+                return Err(PaginationError::from(ErrorKind::FieldValueError(format!(
+                    "Sheet '{}' page '{}' is not contiguous",
+                    index,
+                    sheet.get_page(),
+                ))));
+            }
+        }
+
+        if self.get_sheets().len().ne(&pages) {
+            return Err(PaginationError::from(ErrorKind::FieldValueError(format!(
+                "Book sheets '{}' is not equal to pages '{}'",
+                self.get_sheets().len(),
+                pages,
+            ))));
+        }
+
+        Ok(())
+    }
+
+    /// Get a compact summary of the [`Book`], which is useful to log large books without their records.
+    ///
+    /// ### Arguments:
+    /// *No arguments*
+    ///
+    /// ### Returns:
+    /// A [`String`] with the format `Book { pages: N, size: S, total: T }`, where N is the number of ***sheets***, and S and T are the ***size*** and ***total*** of the first sheet, or 0 if the [`Book`] has no sheets.
+    ///
+    /// ### Example:
+    /// ```rust,no_run
+    /// use page_hunter::*;
+    ///
+    /// let book: Book<u32> = bind_records(&(1..=20).collect::<Vec<u32>>(), 7).unwrap_or_else(|error| {
+    ///     panic!("Error binding records: {:?}", error);
+    /// });
+    ///
+    /// let summary: String = book.summary();
+    /// ```
+    pub fn summary(&self) -> String {
+        let (size, total): (usize, usize) = match self.get_sheets().first() {
+            Some(sheet) => (sheet.get_size(), sheet.get_total()),
+            None => (0, 0),
+        };
+
+        format!(
+            "Book {{ pages: {}, size: {}, total: {} }}",
+            self.get_sheets().len(),
+            size,
+            total
+        )
+    }
+
+    /// Get the index of the page holding a record, which is useful to jump to the page of a specific item.
+    ///
+    /// ### Arguments:
+    /// - **record_index**: The index of the record among all the paginated records, starting from 0.
+    ///
+    /// ### Returns:
+    /// An [`Option`] with the page index `record_index / size`, where size is the ***size*** of the first sheet, or [`None`] if **record_index** is not lower than its ***total*** or the [`Book`] has no sheets.
+    ///
+    /// ### Example:
+    /// ```rust,no_run
+    /// use page_hunter::*;
+    ///
+    /// let book: Book<u32> = bind_records(&(1..=10).collect::<Vec<u32>>(), 3).unwrap_or_else(|error| {
+    ///     panic!("Error binding records: {:?}", error);
+    /// });
+    ///
+    /// let page_index: Option<usize> = book.page_index_of(7);
+    /// ```
+    pub fn page_index_of(&self, record_index: usize) -> Option<usize> {
+        let sheet: &Page<E> = self.get_sheets().first()?;
+
+        match record_index < sheet.get_total() {
+            true => record_index.checked_div(sheet.get_size()),
+            false => None,
+        }
+    }
 }
 
 /// Implementation of [`Clone`] for [`Book`].
@@ -523,7 +2905,7 @@ impl<E> Debug for Book<E>
 where
     E: Debug,
 {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "Book {{ sheets: {:?} }}", self.sheets)
     }
 }
@@ -540,7 +2922,7 @@ impl<E> Display for Book<E>
 where
     E: Debug,
 {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "Book {{ sheets: {:?} }}", self.sheets)
     }
 }
@@ -548,13 +2930,22 @@ where
 /// Implementation of [`IntoIterator`] for [`Book`].
 impl<E> IntoIterator for Book<E> {
     type Item = Page<E>;
-    type IntoIter = std::vec::IntoIter<Self::Item>;
+    type IntoIter = alloc::vec::IntoIter<Self::Item>;
 
     fn into_iter(self) -> Self::IntoIter {
         self.sheets.into_iter()
     }
 }
 
+/// Implementation of [`FromIterator`] for [`Book`], collecting the pages as the sheets of the [`Book`] in order. The sheets are not verified, call [`Book::verify`] to check them.
+impl<E> FromIterator<Page<E>> for Book<E> {
+    fn from_iter<I: IntoIterator<Item = Page<E>>>(iter: I) -> Self {
+        Book {
+            sheets: iter.into_iter().collect(),
+        }
+    }
+}
+
 /// Implementation of [`Serialize`] for [`Book`] if the feature `serde` is enabled.
 #[cfg(feature = "serde")]
 impl<E> Serialize for Book<E>
@@ -628,3 +3019,215 @@ where
         )
     }
 }
+
+/// Implementation of [`PartialSchema`](utoipa5::PartialSchema) for [`Book`] if the feature `utoipa-5` is enabled.
+#[cfg(feature = "utoipa-5")]
+impl<E> PartialSchema5 for Book<E>
+where
+    E: ToSchema5,
+{
+    fn schema() -> RefOr5<Schema5> {
+        ObjectBuilder5::new()
+            .description(Some("Model to represent a book of paginated items."))
+            .property(
+                "sheets",
+                ArrayBuilder5::new()
+                    .description(Some(
+                        "Represents a paginated items as a collection of pages",
+                    ))
+                    .items(<Page<E> as PartialSchema5>::schema()),
+            )
+            .required("sheets")
+            .into()
+    }
+}
+
+/// Implementation of [`ToSchema`](utoipa5::ToSchema) for [`Book`] if the feature `utoipa-5` is enabled.
+#[cfg(feature = "utoipa-5")]
+impl<E> ToSchema5 for Book<E>
+where
+    E: ToSchema5,
+{
+    fn name() -> alloc::borrow::Cow<'static, str> {
+        alloc::borrow::Cow::Borrowed("Book")
+    }
+
+    fn schemas(schemas: &mut Vec<(String, RefOr5<Schema5>)>) {
+        <Page<E> as ToSchema5>::schemas(schemas);
+    }
+}
+
+/// Implementation of [`JsonSchema`] for [`Book`] if the feature `schemars` is enabled.
+#[cfg(feature = "schemars")]
+impl<E> JsonSchema for Book<E>
+where
+    E: JsonSchema,
+{
+    fn schema_name() -> String {
+        format!("Book_of_{}", E::schema_name())
+    }
+
+    fn json_schema(gen: &mut SchemaGenerator) -> SchemarsSchema {
+        let mut object: ObjectValidation = ObjectValidation::default();
+
+        object.properties.insert(
+            String::from("sheets"),
+            SchemarsSchema::Object(SchemaObject {
+                metadata: Some(Box::new(Metadata {
+                    description: Some(String::from(
+                        "Represents a paginated items as a collection of pages",
+                    )),
+                    ..Default::default()
+                })),
+                instance_type: Some(SingleOrVec::Single(Box::new(InstanceType::Array))),
+                array: Some(Box::new(ArrayValidation {
+                    items: Some(SingleOrVec::Single(Box::new(
+                        gen.subschema_for::<Page<E>>(),
+                    ))),
+                    ..Default::default()
+                })),
+                ..Default::default()
+            }),
+        );
+        object.required.insert(String::from("sheets"));
+
+        SchemarsSchema::Object(SchemaObject {
+            metadata: Some(Box::new(Metadata {
+                description: Some(String::from(
+                    "Model to represent a book of paginated items.",
+                )),
+                ..Default::default()
+            })),
+            instance_type: Some(SingleOrVec::Single(Box::new(InstanceType::Object))),
+            object: Some(Box::new(object)),
+            ..Default::default()
+        })
+    }
+}
+
+/// Extension methods for a [`PaginationResult`] of a [`Page`].
+pub trait PaginationResultExt<E> {
+    /// Replace a page out of range error with an empty [`Page`].
+    ///
+    /// ### Arguments:
+    /// - **size**: The maximum number of elements per page of the empty [`Page`].
+    ///
+    /// ### Returns:
+    /// A [`PaginationResult`] with the original [`Page`] if successful, [`Page::empty`] if the page index exceeded the total pages, otherwise the original [`PaginationError`] is returned.
+    ///
+    /// ### Example:
+    ///```rust,no_run
+    /// use page_hunter::*;
+    ///
+    /// let records: Vec<u32> = vec![1, 2, 3, 4, 5];
+    ///
+    /// let pagination_result: PaginationResult<Page<u32>> =
+    ///     paginate_records(&records, 10, 2).or_empty_page(2);
+    /// ````
+    fn or_empty_page(self, size: usize) -> PaginationResult<Page<E>>;
+
+    /// Replace a page out of range error with an empty [`Page`], panicking on any other error.
+    ///
+    /// ### Arguments:
+    /// - **size**: The maximum number of elements per page of the empty [`Page`].
+    ///
+    /// ### Returns:
+    /// The original [`Page`] if successful, or [`Page::empty`] if the page index exceeded the total pages.
+    ///
+    /// ### Panics:
+    /// If the [`PaginationError`] is not a page out of range error.
+    ///
+    /// ### Example:
+    ///```rust,no_run
+    /// use page_hunter::*;
+    ///
+    /// let records: Vec<u32> = vec![1, 2, 3, 4, 5];
+    ///
+    /// let page: Page<u32> = paginate_records(&records, 10, 2).unwrap_or_empty_page(2);
+    /// ````
+    fn unwrap_or_empty_page(self, size: usize) -> Page<E>;
+
+    /// Map the items of a successful [`Page`] into a new [`Page`] with [`Page::checked_map`], keeping any [`PaginationError`].
+    ///
+    /// ### Arguments:
+    /// - **f**: A closure that maps each item `E` into an item `T`.
+    ///
+    /// ### Returns:
+    /// A [`PaginationResult`] with a [`Page`] of the mapped items and the same ***page***, ***size*** and ***total*** if successful, otherwise the original [`PaginationError`] is returned.
+    ///
+    /// ### Example:
+    ///```rust,no_run
+    /// use page_hunter::*;
+    ///
+    /// let records: Vec<u32> = vec![1, 2, 3, 4, 5];
+    ///
+    /// let pagination_result: PaginationResult<Page<String>> =
+    ///     paginate_records(&records, 1, 2).map_page(|record| record.to_string());
+    /// ````
+    fn map_page<T, F>(self, f: F) -> PaginationResult<Page<T>>
+    where
+        F: FnMut(E) -> T;
+
+    /// Map the items of a successful [`Page`] into a new [`Page`] with a fallible closure, keeping any [`PaginationError`].
+    ///
+    /// ### Arguments:
+    /// - **f**: A closure that maps each item `E` into a [`PaginationResult`] of an item `T`.
+    ///
+    /// ### Returns:
+    /// A [`PaginationResult`] with a [`Page`] of the mapped items and the same ***page***, ***size*** and ***total*** if successful. Otherwise the original [`PaginationError`] is returned, or the first error returned by **f** without mapping the remaining items.
+    ///
+    /// ### Example:
+    ///```rust,no_run
+    /// use page_hunter::*;
+    ///
+    /// let records: Vec<&str> = vec!["1", "2", "3", "4", "5"];
+    ///
+    /// let pagination_result: PaginationResult<Page<u32>> =
+    ///     paginate_records(&records, 1, 2).and_then_page(|record| {
+    ///         record.parse::<u32>().map_err(|error| {
+    ///             PaginationError::from(ErrorKind::FieldValueError(error.to_string()))
+    ///         })
+    ///     });
+    /// ````
+    fn and_then_page<T, F>(self, f: F) -> PaginationResult<Page<T>>
+    where
+        F: FnMut(E) -> PaginationResult<T>;
+}
+
+/// Implementation of [`PaginationResultExt`] for [`PaginationResult`]<[`Page`]>.
+impl<E> PaginationResultExt<E> for PaginationResult<Page<E>> {
+    fn or_empty_page(self, size: usize) -> PaginationResult<Page<E>> {
+        match self {
+            Err(error) if error.get_error_kind().is_out_of_range_page() => Ok(Page::empty(size)),
+            result => result,
+        }
+    }
+
+    fn unwrap_or_empty_page(self, size: usize) -> Page<E> {
+        match self.or_empty_page(size) {
+            Ok(page) => page,
+            Err(error) => panic!("Error paginating records: {}", error),
+        }
+    }
+
+    fn map_page<T, F>(self, f: F) -> PaginationResult<Page<T>>
+    where
+        F: FnMut(E) -> T,
+    {
+        self.and_then(|page| page.checked_map(f))
+    }
+
+    fn and_then_page<T, F>(self, f: F) -> PaginationResult<Page<T>>
+    where
+        F: FnMut(E) -> PaginationResult<T>,
+    {
+        self.and_then(|page| {
+            Page::from_results(
+                page.items.into_iter().map(f),
+                page.page,
+                page.size,
+                page.total,
+            )
+        })
+    }
+}
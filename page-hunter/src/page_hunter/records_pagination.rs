@@ -1,5 +1,11 @@
+use alloc::{format, vec::Vec};
+
+use super::errors::{ErrorKind, PaginationError};
 use super::models::*;
 
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
 /// Paginate records into a [`Page`] model.
 ///
 /// #### Arguments:
@@ -10,6 +16,8 @@ use super::models::*;
 /// #### Returns:
 /// A [`PaginationResult`] containing a [`Page`] model of the paginated records `R::Item`.
 ///
+/// When the iterator of **records** reports an exact size, as any [`ExactSizeIterator`] such as the ones of [`Vec`] and slices, the total is read from it. Otherwise the records are iterated once more to count them. The collection is still cloned to iterate its records, so prefer [`paginate_slice`] for a [`Vec`] or a slice.
+///
 /// #### Example:
 /// ```rust,no_run
 /// use page_hunter::*;
@@ -30,17 +38,180 @@ where
     R: IntoIterator + Clone,
     R::Item: Clone,
 {
-    Ok(Page::new(
-        &records
-            .to_owned()
-            .into_iter()
-            .skip(size * page)
-            .take(size)
-            .collect::<Vec<R::Item>>(),
+    let records_iter: R::IntoIter = records.clone().into_iter();
+    let total: usize = records_total(records, &records_iter);
+
+    Page::new_in(
+        records_iter.skip(size.saturating_mul(page)).take(size),
         page,
         size,
-        records.clone().into_iter().count(),
-    )?)
+        total,
+    )
+}
+
+/// Paginate a slice of records into a [`Page`] model, cloning only the records of the page.
+///
+/// #### Arguments:
+/// - **records**: A slice of records `E`, where `E` must implement [`Clone`].
+/// - **page**: The page index.
+/// - **size**: The number of records per page.
+///
+/// #### Returns:
+/// A [`PaginationResult`] containing a [`Page`] model of the paginated records `E`, equal to the one returned by [`paginate_records`] for the same records.
+///
+/// The total is read from the length of the slice and only the subslice of the page is cloned, which is the fastest way to paginate a [`Vec`].
+///
+/// #### Example:
+/// ```rust,no_run
+/// use page_hunter::*;
+///
+/// let records: Vec<u32> = (1..=1_000_000).collect();
+///
+/// let pagination_result: PaginationResult<Page<u32>> = paginate_slice(&records, 3, 10);
+///
+/// let page: Page<u32> = pagination_result.unwrap_or_else(|error| {
+///    panic!("Failed to paginate records: {:?}", error)
+/// });
+/// ````
+pub fn paginate_slice<E>(records: &[E], page: usize, size: usize) -> PaginationResult<Page<E>>
+where
+    E: Clone,
+{
+    let total: usize = records.len();
+
+    let start: usize = page.saturating_mul(size).min(total);
+    let end: usize = start.saturating_add(size).min(total);
+
+    Page::new_in(records[start..end].iter().cloned(), page, size, total)
+}
+
+/// Paginate records into a [`Page`] model counting the pages from the end of the collection, which is useful for reverse chronological feeds.
+///
+/// #### Arguments:
+/// - **records**: A reference to a collection of records `R`, where `R` must implement [`IntoIterator`] and [`Clone`], and `R::Item` must implement [`Clone`].
+/// - **page**: The page index, where 0 is the page holding the last records of the collection.
+/// - **size**: The number of records per page.
+///
+/// #### Returns:
+/// A [`PaginationResult`] containing a [`Page`] model of the paginated records `R::Item`.
+///
+/// Page 0 holds the last **size** records, and increasing page indices move toward the start of the collection, so the last page holds the first records and is the only one that can be partially filled. The records keep their original order within each page, and ***total***, ***pages*** and the links are the same as the ones of [`paginate_records`].
+///
+/// #### Example:
+/// ```rust,no_run
+/// use page_hunter::*;
+///
+/// let records: Vec<u32> = (1..=10).collect();
+///
+/// let pagination_result: PaginationResult<Page<u32>> = paginate_records_rev(&records, 0, 3);
+///
+/// let page: Page<u32> = pagination_result.unwrap_or_else(|error| {
+///    panic!("Failed to paginate records: {:?}", error)
+/// });
+/// ````
+pub fn paginate_records_rev<R>(
+    records: &R,
+    page: usize,
+    size: usize,
+) -> PaginationResult<Page<R::Item>>
+where
+    R: IntoIterator + Clone,
+    R::Item: Clone,
+{
+    let records_iter: R::IntoIter = records.clone().into_iter();
+    let total: usize = records_total(records, &records_iter);
+
+    let end: usize = total.saturating_sub(size.saturating_mul(page));
+    let start: usize = end.saturating_sub(size);
+
+    Page::new_in(
+        records_iter.skip(start).take(end - start),
+        page,
+        size,
+        total,
+    )
+}
+
+/// Paginate records into a [`Page`] model, returning an empty [`Page`] instead of an error when the page index is beyond the last page.
+///
+/// #### Arguments:
+/// - **records**: A reference to a collection of records `R`, where `R` must implement [`IntoIterator`] and [`Clone`], and `R::Item` must implement [`Clone`].
+/// - **page**: The page index.
+/// - **size**: The number of records per page.
+///
+/// #### Returns:
+/// A [`PaginationResult`] containing a [`Page`] model of the paginated records `R::Item`.
+///
+/// Within range, the [`Page`] is the same as the one returned by [`paginate_records`]. Beyond the last page, the [`Page`] has no items, keeps the requested ***page***, the ***size***, the ***total*** and ***pages*** of the records, and its ***previous_page*** points to the last page, like the one returned by `paginate_clamped` with SQLx. It passes [`Page::verify_fields`], so it can be serialized and deserialized back like any other [`Page`]. This is useful for infinite scroll clients that may request one page too many.
+///
+/// #### Example:
+/// ```rust,no_run
+/// use page_hunter::*;
+///
+/// let records: Vec<u32> = vec![1, 2, 3, 4, 5];
+///
+/// let pagination_result: PaginationResult<Page<u32>> = paginate_records_clamped(&records, 10, 2);
+///
+/// let page: Page<u32> = pagination_result.unwrap_or_else(|error| {
+///    panic!("Failed to paginate records: {:?}", error)
+/// });
+/// ````
+pub fn paginate_records_clamped<R>(
+    records: &R,
+    page: usize,
+    size: usize,
+) -> PaginationResult<Page<R::Item>>
+where
+    R: IntoIterator + Clone,
+    R::Item: Clone,
+{
+    let records_iter: R::IntoIter = records.clone().into_iter();
+    let total: usize = records_total(records, &records_iter);
+
+    match Page::<R::Item>::is_valid_index(page, total, size) {
+        true => Page::new_in(records_iter.skip(size * page).take(size), page, size, total),
+        false => Ok(beyond_range_page(page, size, total)),
+    }
+}
+
+/// Paginate owned records into a [`Page`] model, consuming them.
+///
+/// #### Arguments:
+/// - **records**: A collection of records `I`, where `I` must implement [`IntoIterator`].
+/// - **page**: The page index.
+/// - **size**: The number of records per page.
+///
+/// #### Returns:
+/// A [`PaginationResult`] containing a [`Page`] model of the paginated records `I::Item`, equal to the one returned by [`paginate_records`] for the same records.
+///
+/// The records are traversed once and neither the collection nor the records are cloned: the records before the page are counted and dropped, the records of the page are moved into it, and the records after the page are counted. Only the records of the page are held in memory.
+///
+/// #### Example:
+/// ```rust,no_run
+/// use page_hunter::*;
+///
+/// let records: Vec<String> = vec![String::from("a"), String::from("b"), String::from("c")];
+///
+/// let pagination_result: PaginationResult<Page<String>> = paginate_into(records, 1, 2);
+///
+/// let page: Page<String> = pagination_result.unwrap_or_else(|error| {
+///    panic!("Failed to paginate records: {:?}", error)
+/// });
+/// ````
+pub fn paginate_into<I>(records: I, page: usize, size: usize) -> PaginationResult<Page<I::Item>>
+where
+    I: IntoIterator,
+{
+    let mut records_iter: I::IntoIter = records.into_iter();
+
+    let skipped: usize = records_iter
+        .by_ref()
+        .take(size.saturating_mul(page))
+        .count();
+    let items: Vec<I::Item> = records_iter.by_ref().take(size).collect();
+    let total: usize = skipped + items.len() + records_iter.count();
+
+    Page::new_in(items, page, size, total)
 }
 
 /// Bind records into a [`Book`] model.
@@ -71,7 +242,7 @@ where
     R: IntoIterator + Clone,
     R::Item: Clone,
 {
-    let total: usize = records.clone().into_iter().count();
+    let total: usize = records_total(records, &records.clone().into_iter());
 
     let pages: usize = match size.eq(&0) {
         true => 0,
@@ -83,7 +254,7 @@ where
             .map(|page| {
                 Page::new(
                     &records
-                        .to_owned()
+                        .clone()
                         .into_iter()
                         .skip(size * page)
                         .take(size)
@@ -96,3 +267,211 @@ where
             .collect::<PaginationResult<Vec<Page<R::Item>>>>()?,
     ))
 }
+
+/// Bind records into a [`Book`] model, building its pages in parallel with [`rayon`]. Only available when the `rayon` feature is enabled.
+///
+/// #### Arguments:
+/// - **records**: A slice of records `E`, where `E` must implement [`Clone`], [`Send`] and [`Sync`].
+/// - **size**: The number of records per page.
+///
+/// #### Returns:
+/// A [`PaginationResult`] containing a [`Book`] model of the paginated records `E`, identical to the one returned by [`bind_records`] for the same records.
+///
+/// The pages are built on the [`rayon`] global thread pool and collected in order, which is useful when cloning the records is expensive.
+///
+/// #### Example:
+/// ```rust,no_run
+/// use page_hunter::*;
+///
+/// let records: Vec<u32> = (1..=10_000).collect();
+/// let size: usize = 100;
+///
+/// let book_result: PaginationResult<Book<u32>> = par_bind_records(&records, size);
+///
+/// let book: Book<u32> = book_result.unwrap_or_else(|error| {
+///    panic!("Failed to bind records: {:?}", error)
+/// });
+/// ````
+#[cfg(feature = "rayon")]
+pub fn par_bind_records<E>(records: &[E], size: usize) -> PaginationResult<Book<E>>
+where
+    E: Clone + Send + Sync,
+{
+    let total: usize = records.len();
+
+    let pages: usize = match size.eq(&0) {
+        true => 0,
+        false => total.div_ceil(size).max(1),
+    };
+
+    let sheets: Vec<Page<E>> = (0..pages)
+        .into_par_iter()
+        .map(|page| {
+            Page::new_in(
+                records.iter().skip(size * page).take(size).cloned(),
+                page,
+                size,
+                total,
+            )
+        })
+        .collect::<PaginationResult<Vec<Page<E>>>>()?;
+
+    Book::try_new(sheets)
+}
+
+/// Bind records lazily into an iterator of [`Page`] models, which is useful to process the pages of a large collection one at a time instead of holding a whole [`Book`].
+///
+/// #### Arguments:
+/// - **records**: A slice of records `E`, where `E` must implement [`Clone`].
+/// - **size**: The number of records per page.
+///
+/// #### Returns:
+/// An [`Iterator`] yielding a [`PaginationResult`] with a [`Page`] for every page index from 0 to ***pages*** - 1, in order, which are the same pages contained in the [`Book`] returned by [`bind_records`]. Only the records of the yielded page are cloned, from the subslice of the page. A **size** of 0 yields no pages.
+///
+/// #### Example:
+/// ```rust,no_run
+/// use page_hunter::*;
+///
+/// let records: Vec<u32> = vec![1, 2, 3, 4, 5];
+/// let size: usize = 2;
+///
+/// for page_result in bind_records_iter(&records, size) {
+///     let page: Page<u32> = page_result.unwrap_or_else(|error| {
+///        panic!("Failed to bind records: {:?}", error)
+///     });
+/// }
+/// ````
+pub fn bind_records_iter<E>(
+    records: &[E],
+    size: usize,
+) -> impl Iterator<Item = PaginationResult<Page<E>>> + '_
+where
+    E: Clone,
+{
+    let total: usize = records.len();
+
+    let pages: usize = match size.eq(&0) {
+        true => 0,
+        false => total.div_ceil(size).max(1),
+    };
+
+    (0..pages).map(move |page| {
+        let start: usize = (size * page).min(total);
+        let end: usize = start.saturating_add(size).min(total);
+
+        Page::new_in(records[start..end].iter().cloned(), page, size, total)
+    })
+}
+
+/// Merge several [`Book`] models into a single [`Book`], which is useful to combine books paginated separately from sharded sources.
+///
+/// #### Arguments:
+/// - **books**: A slice of [`Book`] of records `E`, where `E` must implement [`Clone`].
+/// - **size**: The number of records per page of the merged [`Book`].
+///
+/// #### Returns:
+/// A [`PaginationResult`] containing a [`Book`] with the records of all **books** concatenated in order and bound again with **size**, so ***total*** and ***pages*** are recomputed for the merged records.
+///
+/// #### Example:
+/// ```rust,no_run
+/// use page_hunter::*;
+///
+/// let first_shard: Book<u32> = bind_records(&vec![1, 2, 3], 2).unwrap_or_else(|error| {
+///    panic!("Failed to bind records: {:?}", error)
+/// });
+/// let second_shard: Book<u32> = bind_records(&vec![4, 5], 2).unwrap_or_else(|error| {
+///    panic!("Failed to bind records: {:?}", error)
+/// });
+///
+/// let book_result: PaginationResult<Book<u32>> = merge_books(&[first_shard, second_shard], 2);
+/// ````
+pub fn merge_books<E>(books: &[Book<E>], size: usize) -> PaginationResult<Book<E>>
+where
+    E: Clone,
+{
+    let records: Vec<E> = books
+        .iter()
+        .flat_map(|book| book.get_sheets())
+        .flat_map(|sheet| sheet.get_items())
+        .cloned()
+        .collect();
+
+    bind_records(&records, size)
+}
+
+/// Validate that a requested page is coherent with a known total number of records, without needing the items.
+///
+/// #### Arguments:
+/// - **page**: The page index.
+/// - **size**: The number of records per page.
+/// - **total**: The total number of records.
+///
+/// #### Returns:
+/// An empty [`PaginationResult`] if the page index is within the pages calculated from **total** and **size**, otherwise the same [`PaginationError`] returned by [`Page::new`] for an out of range page.
+///
+/// This is useful to reject bad requests before querying the items from a database.
+///
+/// #### Example:
+/// ```rust,no_run
+/// use page_hunter::*;
+///
+/// let validation_result: PaginationResult<()> = validate_request(2, 2, 5);
+///
+/// validation_result.unwrap_or_else(|error| {
+///    panic!("Invalid pagination request: {:?}", error)
+/// });
+/// ````
+pub fn validate_request(page: usize, size: usize, total: usize) -> PaginationResult<()> {
+    if !Page::<()>::is_valid_index(page, total, size) {
+        return Err(PaginationError::from(ErrorKind::OutOfRangePage {
+            requested: page,
+            total_pages: Page::<()>::page_count_for_total(total, size),
+        }));
+    }
+
+    Ok(())
+}
+
+/// Convert a count of records returned by a database, such as the `i64` result of a `COUNT(*)` query, into a total number of records.
+///
+/// #### Arguments:
+/// - **count**: The count of records.
+///
+/// #### Returns:
+/// A [`PaginationResult`] containing the total number of records as [`usize`], or a [`PaginationError`] if **count** is negative or exceeds [`usize::MAX`] on the target platform.
+///
+/// #### Example:
+/// ```rust,no_run
+/// use page_hunter::*;
+///
+/// let total_result: PaginationResult<usize> = total_from_count(100_i64);
+/// ````
+pub fn total_from_count<C>(count: C) -> PaginationResult<usize>
+where
+    C: TryInto<usize> + Copy + core::fmt::Display,
+{
+    count.try_into().map_err(|_| {
+        PaginationError::from(ErrorKind::FieldValueError(format!(
+            "Total records count '{}' is out of range",
+            count,
+        )))
+    })
+}
+
+/// Build the empty [`Page`] returned for a page index beyond the last page, linking back to the last page, which passes [`Page::verify_fields`]. Shared by [`paginate_records_clamped`] and `paginate_clamped` with SQLx.
+pub(crate) fn beyond_range_page<E>(page: usize, size: usize, total: usize) -> Page<E> {
+    let pages: usize = Page::<E>::page_count_for_total(total, size);
+
+    Page::new_unchecked(Vec::new(), page, size, total, pages, Some(pages - 1), None)
+}
+
+/// Get the total number of records of a collection from the size hint of its iterator when it is exact, as reported by every [`ExactSizeIterator`], which avoids iterating the records twice. Otherwise the records are iterated once more to count them.
+fn records_total<R>(records: &R, records_iter: &R::IntoIter) -> usize
+where
+    R: IntoIterator + Clone,
+{
+    match records_iter.size_hint() {
+        (lower, Some(upper)) if lower.eq(&upper) => lower,
+        _ => records.clone().into_iter().count(),
+    }
+}
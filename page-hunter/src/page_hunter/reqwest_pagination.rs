@@ -0,0 +1,141 @@
+#[cfg(feature = "reqwest")]
+use super::errors::{ErrorKind, PaginationError};
+
+#[cfg(feature = "reqwest")]
+use super::models::{Page, PaginationResult};
+
+#[cfg(feature = "reqwest")]
+use reqwest::Client;
+
+#[cfg(feature = "reqwest")]
+use serde::de::DeserializeOwned;
+
+#[cfg(feature = "reqwest")]
+use std::marker::PhantomData;
+
+/// Fetch a single [`Page`] from a paginated HTTP API using [`reqwest`].
+/// The ***page*** and ***size*** values are sent as query parameters and the JSON response body is deserialized into a [`Page`] model, verifying its fields.
+/// Only available when the `reqwest` feature is enabled.
+///
+/// ### Arguments:
+/// - **client**: A reference to a [`reqwest::Client`].
+/// - **url**: The URL of the paginated endpoint.
+/// - **page**: The page index.
+/// - **size**: The number of records per page.
+///
+/// ### Returns:
+/// A [`PaginationResult`] containing a [`Page`] model of the fetched records `E`, where `E` must implement [`DeserializeOwned`].
+/// Connection failures, non-success status codes and invalid response bodies are returned as [`ErrorKind::ReqwestError`](crate::ErrorKind::ReqwestError).
+/// A response whose ***page*** differs from the requested **page**, such as a server ignoring the query parameters, is returned as [`ErrorKind::FieldValueError`].
+///
+/// ### Example:
+/// ```rust,no_run
+/// use page_hunter::*;
+///
+/// # async fn run() -> PaginationResult<()> {
+/// let client: reqwest::Client = reqwest::Client::new();
+///
+/// let page: Page<String> = fetch_page(&client, "http://localhost:8080/users", 0, 10).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[cfg(feature = "reqwest")]
+pub async fn fetch_page<E>(
+    client: &Client,
+    url: &str,
+    page: usize,
+    size: usize,
+) -> PaginationResult<Page<E>>
+where
+    E: DeserializeOwned,
+{
+    let fetched: Page<E> = client
+        .get(url)
+        .query(&[("page", page), ("size", size)])
+        .send()
+        .await?
+        .error_for_status()?
+        .json::<Page<E>>()
+        .await?;
+
+    match fetched.get_page().eq(&page) {
+        true => Ok(fetched),
+        false => Err(PaginationError::from(ErrorKind::FieldValueError(format!(
+            "Fetched page index error: expected '{}', found '{}'",
+            page,
+            fetched.get_page()
+        )))),
+    }
+}
+
+/// Walk all the pages of a paginated HTTP API using [`reqwest`], following the ***next_page*** of each fetched [`Page`].
+/// It is created by [`walk_pages`]. Only available when the `reqwest` feature is enabled.
+#[cfg(feature = "reqwest")]
+#[derive(Clone, Debug)]
+pub struct PageWalker<E> {
+    client: Client,
+    url: String,
+    size: usize,
+    next_page: Option<usize>,
+    _items: PhantomData<fn() -> E>,
+}
+
+#[cfg(feature = "reqwest")]
+impl<E> PageWalker<E>
+where
+    E: DeserializeOwned,
+{
+    /// Fetch the next [`Page`] of the walk.
+    ///
+    /// ### Returns:
+    /// - [`Some`] containing a [`PaginationResult`] with the next [`Page`] model while there are pages left.
+    /// - [`None`] once the last page has been fetched or after an error has been returned, including a fetched [`Page`] whose index differs from the requested one, so a server ignoring the page parameter cannot make the walk loop forever.
+    pub async fn next(&mut self) -> Option<PaginationResult<Page<E>>> {
+        let page: usize = self.next_page.take()?;
+
+        let result: PaginationResult<Page<E>> =
+            fetch_page(&self.client, &self.url, page, self.size).await;
+        if let Ok(fetched) = &result {
+            self.next_page = fetched.get_next_page();
+        }
+
+        Some(result)
+    }
+}
+
+/// Create a [`PageWalker`] to iterate asynchronously over all the pages of a paginated HTTP API, starting from the first page.
+/// Only available when the `reqwest` feature is enabled.
+///
+/// ### Arguments:
+/// - **client**: A reference to a [`reqwest::Client`]. It is cloned into the walker.
+/// - **url**: The URL of the paginated endpoint.
+/// - **size**: The number of records per page.
+///
+/// ### Returns:
+/// A [`PageWalker`] of records `E`.
+///
+/// ### Example:
+/// ```rust,no_run
+/// use page_hunter::*;
+///
+/// # async fn run() -> PaginationResult<()> {
+/// let client: reqwest::Client = reqwest::Client::new();
+///
+/// let mut walker: PageWalker<String> = walk_pages(&client, "http://localhost:8080/users", 10);
+/// while let Some(page) = walker.next().await {
+///     let page: Page<String> = page?;
+///     println!("{}", page);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[cfg(feature = "reqwest")]
+pub fn walk_pages<E>(client: &Client, url: &str, size: usize) -> PageWalker<E> {
+    PageWalker {
+        client: client.clone(),
+        url: url.to_string(),
+        size,
+        next_page: Some(0),
+        _items: PhantomData,
+    }
+}
@@ -0,0 +1,54 @@
+#[cfg(feature = "stream")]
+use super::models::{Page, PaginationResult};
+
+#[cfg(feature = "stream")]
+use futures_util::stream::{Stream, StreamExt};
+
+/// Paginate the records of an asynchronous [`Stream`] into a [`Page`] model, consuming it.
+/// Only available when the `stream` feature is enabled.
+///
+/// ### Arguments:
+/// - **stream**: A stream of records `S`, where `S` must implement [`Stream`].
+/// - **page**: The page index.
+/// - **size**: The number of records per page.
+///
+/// ### Returns:
+/// A [`PaginationResult`] containing a [`Page`] model of the paginated records `S::Item`, equal to the one returned by [`paginate_into`](crate::paginate_into) for the same records.
+///
+/// The stream is polled to completion to count the total of records: the records before the page are counted and dropped, the records of the page are moved into it, and the records after the page are counted. Only the records of the page are held in memory.
+///
+/// ### Example:
+/// ```rust,no_run
+/// use page_hunter::*;
+///
+/// # async fn run() -> PaginationResult<()> {
+/// let stream = futures_util::stream::iter(vec![1, 2, 3, 4, 5]);
+///
+/// let page: Page<u32> = paginate_stream(stream, 1, 2).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[cfg(feature = "stream")]
+pub async fn paginate_stream<S>(
+    stream: S,
+    page: usize,
+    size: usize,
+) -> PaginationResult<Page<S::Item>>
+where
+    S: Stream,
+{
+    futures_util::pin_mut!(stream);
+
+    let start: usize = size.saturating_mul(page);
+    let mut total: usize = 0;
+    let mut items: Vec<S::Item> = Vec::new();
+
+    while let Some(record) = stream.next().await {
+        if total.ge(&start) && items.len().lt(&size) {
+            items.push(record);
+        }
+        total += 1;
+    }
+
+    Page::new_in(items, page, size, total)
+}
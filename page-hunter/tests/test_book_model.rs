@@ -214,4 +214,268 @@ mod test_book {
             "FIELD VALUE ERROR- Next page index error: expected 'None', found 'Some(3)' at line 1 column 270"
         );
     }
+
+    /// Test [`Book`] find_record method.
+    #[test]
+    fn test_book_find_record() {
+        let records: Vec<u32> = vec![1, 2, 3, 4, 5];
+        let size: usize = 2;
+
+        let page_1: Page<u32> = Page::new(&records[0..2].to_vec(), 0, size, records.len()).unwrap();
+        let page_2: Page<u32> = Page::new(&records[2..4].to_vec(), 1, size, records.len()).unwrap();
+        let page_3: Page<u32> = Page::new(&records[4..5].to_vec(), 2, size, records.len()).unwrap();
+
+        let book: Book<u32> = Book::new(&vec![page_1, page_2, page_3]);
+
+        assert_eq!(book.find_record(|record| *record == 4), Some((1, 3)));
+        assert_eq!(book.find_record(|record| *record % 2 == 1), Some((0, 0)));
+        assert_eq!(book.find_record(|record| *record == 10), None);
+    }
+
+    /// Test [`Book`] dedup_by_key method with overlapping sheets.
+    #[test]
+    fn test_book_dedup_by_key() {
+        let page_1: Page<u32> = Page::new(&vec![1, 2], 0, 2, 6).unwrap();
+        let page_2: Page<u32> = Page::new(&vec![2, 3], 1, 2, 6).unwrap();
+        let page_3: Page<u32> = Page::new(&vec![4, 5], 2, 2, 6).unwrap();
+
+        let mut book: Book<u32> = Book::new(&vec![page_1, page_2, page_3]);
+        assert!(book.dedup_by_key(|record| *record).is_ok());
+
+        assert_eq!(book.get_sheets().len(), 3);
+        assert_eq!(book.get_sheets()[0].get_items(), &vec![1, 2]);
+        assert_eq!(book.get_sheets()[1].get_items(), &vec![3, 4]);
+        assert_eq!(book.get_sheets()[2].get_items(), &vec![5]);
+
+        for sheet in book.get_sheets() {
+            assert_eq!(sheet.get_size(), 2);
+            assert_eq!(sheet.get_total(), 5);
+            assert_eq!(sheet.get_pages(), 3);
+        }
+        assert_eq!(book.get_sheets()[2].get_next_page(), None);
+    }
+
+    /// Test [`Book`] dedup_by_key method with a book holding only some of the pages.
+    #[test]
+    fn test_book_dedup_by_key_partial_book() {
+        let page_1: Page<u32> = Page::new(&vec![1, 2], 0, 2, 6).unwrap();
+        let page_2: Page<u32> = Page::new(&vec![2, 3], 1, 2, 6).unwrap();
+
+        let mut book: Book<u32> = Book::new(&vec![page_1, page_2]);
+        assert!(book.dedup_by_key(|record| *record).is_ok());
+
+        assert_eq!(book.get_sheets().len(), 2);
+        assert_eq!(book.get_sheets()[0].get_items(), &vec![1, 2]);
+        assert_eq!(book.get_sheets()[1].get_items(), &vec![3]);
+        for sheet in book.get_sheets() {
+            assert!(sheet.verify_fields().is_ok());
+            assert_eq!(sheet.get_total(), 3);
+            assert_eq!(sheet.get_pages(), 2);
+        }
+        assert!(book.verify().is_ok());
+
+        let page_2: Page<u32> = Page::new(&vec![3, 4], 1, 2, 10).unwrap();
+        let page_3: Page<u32> = Page::new(&vec![4, 5], 2, 2, 10).unwrap();
+
+        let mut book: Book<u32> = Book::new(&vec![page_2, page_3]);
+        assert!(book.dedup_by_key(|record| *record).is_ok());
+
+        assert_eq!(book.get_sheets()[0].get_page(), 1);
+        assert_eq!(book.get_sheets()[1].get_items(), &vec![5]);
+        assert_eq!(book.get_sheets()[1].get_total(), 5);
+        assert_eq!(book.get_sheets()[1].get_next_page(), None);
+    }
+
+    /// Test [`Book`] dedup_by_key method without duplicated records.
+    #[test]
+    fn test_book_dedup_by_key_without_duplicates() {
+        let page_1: Page<u32> = Page::new(&vec![1, 2], 0, 2, 10).unwrap();
+        let page_2: Page<u32> = Page::new(&vec![3, 4], 1, 2, 10).unwrap();
+
+        let mut book: Book<u32> = Book::new(&vec![page_1.clone(), page_2.clone()]);
+        assert!(book.dedup_by_key(|record| *record).is_ok());
+        assert_eq!(book.get_sheets(), &vec![page_1, page_2]);
+
+        let mut empty_book: Book<u32> = Book::default();
+        assert!(empty_book.dedup_by_key(|record| *record).is_ok());
+        assert_eq!(empty_book.get_sheets().len(), 0);
+    }
+
+    /// Test [`Book`] paginate_sheets method.
+    #[test]
+    fn test_book_paginate_sheets() {
+        let records: Vec<u32> = (1..=20).collect();
+        let book: Book<u32> = bind_records(&records, 2).unwrap();
+        assert_eq!(book.get_sheets().len(), 10);
+
+        let sheets_page: Page<Page<u32>> = book.paginate_sheets(1, 3).unwrap();
+        assert_eq!(sheets_page.get_items().len(), 3);
+        assert_eq!(sheets_page.get_items()[0].get_page(), 3);
+        assert_eq!(sheets_page.get_items()[0].get_items(), &vec![7, 8]);
+        assert_eq!(sheets_page.get_items()[2].get_page(), 5);
+        assert_eq!(sheets_page.get_page(), 1);
+        assert_eq!(sheets_page.get_size(), 3);
+        assert_eq!(sheets_page.get_total(), 10);
+        assert_eq!(sheets_page.get_pages(), 4);
+
+        let last_sheets_page: Page<Page<u32>> = book.paginate_sheets(3, 3).unwrap();
+        assert_eq!(last_sheets_page.get_items().len(), 1);
+        assert_eq!(last_sheets_page.get_items()[0].get_page(), 9);
+        assert_eq!(last_sheets_page.get_next_page(), None);
+
+        assert!(book.paginate_sheets(4, 3).is_err());
+    }
+
+    /// Test [`Book`] verify method.
+    #[test]
+    fn test_book_verify() {
+        let records: Vec<u32> = (1..=5).collect();
+        assert!(bind_records(&records, 2).unwrap().verify().is_ok());
+        assert!(Book::<u32>::default().verify().is_ok());
+
+        let mismatched_total: Book<u32> = Book::new(&vec![
+            Page::new(&vec![1, 2], 0, 2, 3).unwrap(),
+            Page::new(&vec![3, 4], 1, 2, 5).unwrap(),
+        ]);
+        let error: PaginationError = mismatched_total.verify().unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "FIELD VALUE ERROR- Sheet '1' total '5' is not equal to book total '3'"
+        );
+
+        let mismatched_size: Book<u32> = Book::new(&vec![
+            Page::new(&vec![1, 2], 0, 2, 4).unwrap(),
+            Page::new(&vec![4], 1, 3, 4).unwrap(),
+        ]);
+        assert!(mismatched_size.verify().is_err());
+
+        let gap: Book<u32> = Book::new(&vec![
+            Page::new(&vec![1, 2], 0, 2, 5).unwrap(),
+            Page::new(&vec![5], 2, 2, 5).unwrap(),
+        ]);
+        let error: PaginationError = gap.verify().unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "FIELD VALUE ERROR- Sheet '1' page '2' is not contiguous"
+        );
+
+        let missing_sheets: Book<u32> = Book::new(&vec![
+            Page::new(&vec![1, 2], 0, 2, 5).unwrap(),
+            Page::new(&vec![3, 4], 1, 2, 5).unwrap(),
+        ]);
+        let error: PaginationError = missing_sheets.verify().unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "FIELD VALUE ERROR- Book sheets '2' is not equal to pages '3'"
+        );
+    }
+
+    /// Test [`Book`] FromIterator implementation.
+    #[test]
+    fn test_book_from_iterator() {
+        let records: Vec<u32> = (1..=5).collect();
+
+        let book: Book<u32> = (0..3)
+            .map(|page| paginate_records(&records, page, 2).unwrap())
+            .collect();
+        assert_eq!(book.get_sheets().len(), 3);
+        assert_eq!(book.get_sheets()[2].get_items(), &vec![5]);
+        assert!(book.verify().is_ok());
+        assert_eq!(
+            book.get_sheets(),
+            bind_records(&records, 2).unwrap().get_sheets()
+        );
+
+        let empty: Book<u32> = Vec::<Page<u32>>::new().into_iter().collect();
+        assert!(empty.get_sheets().is_empty());
+    }
+
+    /// Test [`Book`] try_new with valid sheets.
+    #[test]
+    fn test_book_try_new() {
+        let sheets: Vec<Page<u32>> = vec![
+            Page::new(&vec![1, 2], 0, 2, 5).unwrap(),
+            Page::new(&vec![3, 4], 1, 2, 5).unwrap(),
+            Page::new(&vec![5], 2, 2, 5).unwrap(),
+        ];
+
+        let book: Book<u32> = Book::try_new(sheets.clone()).unwrap();
+        assert_eq!(book.get_sheets(), &sheets);
+
+        let empty: Book<u32> = Book::try_new(Vec::new()).unwrap();
+        assert!(empty.get_sheets().is_empty());
+    }
+
+    /// Test [`Book`] try_new with a gap in the page indices.
+    #[test]
+    fn test_book_try_new_with_page_gap() {
+        let sheets: Vec<Page<u32>> = vec![
+            Page::new(&vec![1, 2], 0, 2, 7).unwrap(),
+            Page::new(&vec![3, 4], 1, 2, 7).unwrap(),
+            Page::new(&vec![7], 3, 2, 7).unwrap(),
+        ];
+
+        let error: PaginationError = Book::try_new(sheets).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "FIELD VALUE ERROR- Sheet '2' page '3' is not contiguous"
+        );
+    }
+
+    /// Test [`Book`] try_new with sheets sorted after being gathered out of order.
+    #[test]
+    fn test_book_try_new_with_sorted_sheets() {
+        let records: Vec<u32> = (1..=7).collect();
+
+        let mut sheets: Vec<Page<u32>> = [2, 0, 3, 1]
+            .into_iter()
+            .map(|page| paginate_records(&records, page, 2).unwrap())
+            .collect();
+        assert!(Book::try_new(sheets.clone()).is_err());
+
+        sheets.sort();
+
+        let book: Book<u32> = Book::try_new(sheets).unwrap();
+        assert_eq!(
+            book.get_sheets()
+                .iter()
+                .map(|sheet| sheet.get_page())
+                .collect::<Vec<usize>>(),
+            vec![0, 1, 2, 3]
+        );
+        assert_eq!(
+            book.get_sheets(),
+            bind_records(&records, 2).unwrap().get_sheets()
+        );
+    }
+
+    /// Test [`Book`] summary method.
+    #[test]
+    fn test_book_summary() {
+        let records: Vec<u32> = (1..=20).collect();
+        let book: Book<u32> = bind_records(&records, 7).unwrap();
+        assert_eq!(book.get_sheets().len(), 3);
+
+        assert_eq!(book.summary(), "Book { pages: 3, size: 7, total: 20 }");
+        assert_eq!(
+            Book::<u32>::new(&vec![]).summary(),
+            "Book { pages: 0, size: 0, total: 0 }"
+        );
+    }
+
+    /// Test [`Book`] page_index_of method.
+    #[test]
+    fn test_book_page_index_of() {
+        let records: Vec<u32> = (1..=10).collect();
+        let book: Book<u32> = bind_records(&records, 3).unwrap();
+
+        assert_eq!(book.page_index_of(7), Some(2));
+        assert_eq!(book.get_sheets()[2].get_items()[7 % 3], records[7]);
+        assert_eq!(book.page_index_of(0), Some(0));
+        assert_eq!(book.page_index_of(9), Some(3));
+        assert_eq!(book.page_index_of(10), None);
+
+        let empty_book: Book<u32> = Book::new(&vec![]);
+        assert_eq!(empty_book.page_index_of(0), None);
+    }
 }
@@ -97,4 +97,171 @@ mod test_errors {
         let pagination_error: PaginationError = error_kind.into();
         assert!(pagination_error.get_error_kind().is_field_value_error());
     }
+
+    /// Test [`std::io::Error`] from [`PaginationError`] with [`ErrorKind::FieldValueError`].
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_io_error_from_field_value_error() {
+        use std::io::{Error as IoError, ErrorKind as IoErrorKind};
+
+        let pagination_error: PaginationError =
+            PaginationError::from(ErrorKind::FieldValueError(String::from("Invalid value")));
+        let io_error: IoError = pagination_error.into();
+
+        assert_eq!(io_error.kind(), IoErrorKind::InvalidData);
+        assert_eq!(io_error.to_string(), "FIELD VALUE ERROR- Invalid value");
+    }
+
+    /// Test [`std::io::Error`] from [`PaginationError`] with [`ErrorKind::SQLxError`].
+    #[cfg(any(feature = "pg-sqlx", feature = "mysql-sqlx"))]
+    #[test]
+    fn test_io_error_from_sqlx_error() {
+        use std::io::{Error as IoError, ErrorKind as IoErrorKind};
+
+        let pagination_error: PaginationError = PaginationError::from(SqlxError::PoolClosed);
+        let io_error: IoError = pagination_error.into();
+
+        assert_eq!(io_error.kind(), IoErrorKind::Other);
+        assert_eq!(
+            io_error.to_string(),
+            "SQLX ERROR- attempted to acquire a connection on a closed pool"
+        );
+    }
+
+    /// Test [`ErrorKind`] `description` method for [`ErrorKind::FieldValueError`].
+    #[test]
+    fn test_error_kind_field_value_error_description() {
+        let error_kind: ErrorKind = ErrorKind::FieldValueError(String::from("Invalid value"));
+        assert_eq!(error_kind.description(), "Invalid value");
+    }
+
+    /// Test [`ErrorKind`] `description` method for [`ErrorKind::SQLxError`].
+    #[cfg(any(feature = "pg-sqlx", feature = "mysql-sqlx"))]
+    #[test]
+    fn test_error_kind_sqlx_error_description() {
+        let error_kind: ErrorKind = ErrorKind::SQLxError(SqlxError::PoolClosed);
+        assert_eq!(
+            error_kind.description(),
+            "attempted to acquire a connection on a closed pool"
+        );
+    }
+
+    /// Test [`PaginationError`] with_context and get_context methods.
+    #[test]
+    fn test_pagination_error_context() {
+        let pagination_error: PaginationError =
+            PaginationError::from(ErrorKind::FieldValueError(String::from("Invalid value")));
+        assert_eq!(pagination_error.get_context(), None);
+
+        let pagination_error: PaginationError =
+            pagination_error.with_context("FETCH query failed for page '2' and size '3'");
+        assert_eq!(
+            pagination_error.get_context(),
+            Some("FETCH query failed for page '2' and size '3'")
+        );
+        assert_eq!(
+            format!("{}", pagination_error),
+            "FIELD VALUE ERROR- Invalid value (FETCH query failed for page '2' and size '3')"
+        );
+        assert_eq!(
+            format!("{:?}", pagination_error),
+            "PaginationError { kind: FieldValueError(\"Invalid value\"), context: \"FETCH query failed for page '2' and size '3'\" }"
+        );
+    }
+
+    /// Test [`ErrorKind::OutOfRangePage`] methods and formatting.
+    #[test]
+    fn test_error_kind_out_of_range_page() {
+        let error_kind: ErrorKind = ErrorKind::OutOfRangePage {
+            requested: 5,
+            total_pages: 4,
+        };
+        assert!(error_kind.is_out_of_range_page());
+        assert!(!error_kind.is_field_value_error());
+        assert!(!ErrorKind::FieldValueError(String::from("Invalid value")).is_out_of_range_page());
+
+        assert_eq!(
+            error_kind.description(),
+            "Page index '5' exceeds total pages '4'"
+        );
+        assert_eq!(
+            format!("{}", error_kind),
+            "OUT OF RANGE PAGE ERROR- Page index '5' exceeds total pages '4'"
+        );
+        assert_eq!(
+            format!("{:?}", error_kind),
+            "OutOfRangePage { requested: 5, total_pages: 4 }"
+        );
+
+        #[cfg(feature = "std")]
+        {
+            let io_error: std::io::Error = PaginationError::from(error_kind).into();
+            assert_eq!(io_error.kind(), std::io::ErrorKind::InvalidInput);
+        }
+    }
+
+    /// Test [`ErrorKind::OutOfRangePage`] is raised for a page index out of range.
+    #[test]
+    fn test_out_of_range_page_raised() {
+        let records: Vec<u32> = vec![1, 2, 3, 4, 5];
+
+        let error: PaginationError = paginate_records(&records, 3, 2).unwrap_err();
+        assert!(matches!(
+            error.get_error_kind(),
+            ErrorKind::OutOfRangePage {
+                requested: 3,
+                total_pages: 3
+            }
+        ));
+
+        let error: PaginationError = validate_request(3, 2, 5).unwrap_err();
+        assert!(error.get_error_kind().is_out_of_range_page());
+
+        let error: PaginationError = Page::new(&vec![1, 2], 0, 2, 5)
+            .unwrap()
+            .with_items(vec![1, 2, 3])
+            .unwrap_err();
+        assert!(!error.get_error_kind().is_out_of_range_page());
+    }
+
+    /// Test [`ErrorKind::FromRowError`] display, debug, description and check methods.
+    #[cfg(any(feature = "pg-sqlx", feature = "mysql-sqlx"))]
+    #[test]
+    fn test_error_kind_from_row_error() {
+        let error_kind: ErrorKind =
+            ErrorKind::FromRowError(String::from("no column found for name: age"));
+        assert!(error_kind.is_from_row_error());
+        assert!(!error_kind.is_sqlx_error());
+        assert!(!error_kind.is_field_value_error());
+        assert!(!ErrorKind::SQLxError(SqlxError::PoolClosed).is_from_row_error());
+
+        assert_eq!(error_kind.description(), "no column found for name: age");
+        assert_eq!(
+            format!("{}", error_kind),
+            "FROM ROW ERROR- no column found for name: age"
+        );
+        assert_eq!(
+            format!("{:?}", error_kind),
+            "FromRowError(\"no column found for name: age\")"
+        );
+
+        let io_error: std::io::Error = PaginationError::from(error_kind).into();
+        assert_eq!(io_error.kind(), std::io::ErrorKind::InvalidData);
+    }
+
+    /// Test [`PaginationError`] `as_sqlx_error` method.
+    #[cfg(any(feature = "pg-sqlx", feature = "mysql-sqlx"))]
+    #[test]
+    fn test_pagination_error_as_sqlx_error() {
+        let pagination_error: PaginationError =
+            PaginationError::from(SqlxError::RowNotFound).with_context("FETCH");
+        assert!(matches!(
+            pagination_error.as_sqlx_error(),
+            Some(SqlxError::RowNotFound)
+        ));
+
+        let field_value_error: PaginationError =
+            ErrorKind::FieldValueError(String::from("Invalid value")).into();
+        assert!(field_value_error.as_sqlx_error().is_none());
+    }
 }
@@ -46,7 +46,7 @@ mod test_page_model {
         let pagination_error: PaginationError = pagination_result.unwrap_err();
         assert!(pagination_error
             .to_string()
-            .eq("FIELD VALUE ERROR- Page index '3' exceeds total pages '3'"));
+            .eq("OUT OF RANGE PAGE ERROR- Page index '3' exceeds total pages '3'"));
     }
 
     /// Test [`Page`] constructor with invalid `items` value: `items` length exceeds `total` elements.
@@ -209,6 +209,8 @@ mod test_page_model {
 
     /// Test serialization and deserialization of [`Page`].
     #[cfg(feature = "serde")]
+    #[cfg(not(feature = "serde-camel-case"))]
+    #[cfg(not(feature = "serde-skip-null-links"))]
     #[test]
     fn test_page_model_serialization_and_deserialization() {
         use serde::{Deserialize, Serialize};
@@ -255,6 +257,34 @@ mod test_page_model {
         assert_eq!(deserialized.get_next_page(), next_page);
     }
 
+    /// Test serialization of [`Page`] without null links.
+    #[cfg(feature = "serde-skip-null-links")]
+    #[cfg(not(feature = "serde-camel-case"))]
+    #[test]
+    fn test_page_model_serde_skip_null_links() {
+        let first_page: Page<u32> = Page::new(&vec![1, 2], 0, 2, 5).unwrap();
+
+        let serialized: String = serde_json::to_string(&first_page).unwrap();
+        assert!(!serialized.contains("previous_page"));
+        assert_eq!(
+            serialized,
+            r#"{"items":[1,2],"page":0,"size":2,"total":5,"pages":3,"next_page":1}"#
+        );
+
+        let deserialized: Page<u32> = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(deserialized, first_page);
+
+        let single_page: Page<u32> = Page::new(&vec![1, 2], 0, 2, 2).unwrap();
+        let serialized: String = serde_json::to_string(&single_page).unwrap();
+        assert_eq!(
+            serialized,
+            r#"{"items":[1,2],"page":0,"size":2,"total":2,"pages":1}"#
+        );
+
+        let deserialized: Page<u32> = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(deserialized, single_page);
+    }
+
     /// Test deserialization of [`Page`] with invalid pages.
     #[cfg(feature = "serde")]
     #[test]
@@ -353,4 +383,1461 @@ mod test_page_model {
 
         assert!(result.is_err());
     }
+
+    /// Test [`Page`] split method when the page holds the full dataset.
+    #[test]
+    fn test_page_model_split() {
+        let page_model: Page<u32> = Page::new(&vec![1, 2, 3, 4, 5], 0, 5, 5).unwrap();
+
+        let split_result: PaginationResult<Book<u32>> = page_model.split(2);
+        assert!(split_result.is_ok());
+
+        let book: Book<u32> = split_result.unwrap();
+        assert_eq!(book.get_sheets().len(), 3);
+        assert_eq!(book.get_sheets()[0].get_items(), &vec![1, 2]);
+        assert_eq!(book.get_sheets()[1].get_items(), &vec![3, 4]);
+        assert_eq!(book.get_sheets()[2].get_items(), &vec![5]);
+        assert_eq!(book.get_sheets()[2].get_size(), 2);
+        assert_eq!(book.get_sheets()[2].get_total(), 5);
+        assert_eq!(book.get_sheets()[2].get_pages(), 3);
+    }
+
+    /// Test [`Page`] split method when the page does not hold the full dataset.
+    #[test]
+    fn test_page_model_split_with_missing_items() {
+        let page_model: Page<u32> = Page::new(&vec![1, 2], 0, 2, 5).unwrap();
+
+        let split_result: PaginationResult<Book<u32>> = page_model.split(1);
+        assert!(split_result.is_err());
+
+        let pagination_error: PaginationError = split_result.unwrap_err();
+        assert_eq!(
+            pagination_error.to_string(),
+            "FIELD VALUE ERROR- Page cannot be split: items length '2' is not equal to total elements '5'"
+        );
+    }
+
+    /// Test [`Page`] constructor from an iterator produces the same page as [`Page::new`].
+    #[test]
+    fn test_page_model_new_in() {
+        let records: Vec<u32> = vec![1, 2, 3, 4, 5];
+        let page: usize = 1;
+        let size: usize = 2;
+        let total_elements: usize = 5;
+
+        let expected_page_model: Page<u32> =
+            Page::new(&vec![3, 4], page, size, total_elements).unwrap();
+
+        let pagination_result: PaginationResult<Page<u32>> = Page::new_in(
+            records.into_iter().skip(page * size).take(size),
+            page,
+            size,
+            total_elements,
+        );
+        assert!(pagination_result.is_ok());
+
+        let page_model: Page<u32> = pagination_result.unwrap();
+        assert_eq!(page_model.get_items(), expected_page_model.get_items());
+        assert_eq!(page_model.get_page(), expected_page_model.get_page());
+        assert_eq!(page_model.get_size(), expected_page_model.get_size());
+        assert_eq!(page_model.get_total(), expected_page_model.get_total());
+        assert_eq!(page_model.get_pages(), expected_page_model.get_pages());
+        assert_eq!(
+            page_model.get_previous_page(),
+            expected_page_model.get_previous_page()
+        );
+        assert_eq!(
+            page_model.get_next_page(),
+            expected_page_model.get_next_page()
+        );
+        assert!(page_model.get_items().capacity() >= size);
+    }
+
+    /// Test [`Page`] constructor from an iterator with invalid items length.
+    #[test]
+    fn test_page_model_new_in_error() {
+        let pagination_result: PaginationResult<Page<u32>> = Page::new_in(vec![1], 0, 2, 3);
+        assert!(pagination_result.is_err());
+
+        let pagination_error: PaginationError = pagination_result.unwrap_err();
+        assert_eq!(
+            pagination_error.to_string(),
+            "FIELD VALUE ERROR- Items length '1' is not equal to page size '2' for an intermediate page '0'"
+        );
+    }
+
+    /// Test [`Page`] new_in method with a huge size and an iterator without an upper size hint.
+    #[test]
+    fn test_page_model_new_in_huge_size() {
+        let records: Vec<u32> = vec![1, 2, 3];
+
+        let page_model: Page<u32> =
+            Page::new_in(records.iter().copied().filter(|_| false), 0, usize::MAX, 0).unwrap();
+        assert!(page_model.get_items().is_empty());
+        assert_eq!(page_model.get_size(), usize::MAX);
+
+        let pagination_result: PaginationResult<Page<u32>> = Page::new_in(
+            records.iter().copied().filter(|record| record % 2 == 1),
+            0,
+            10_000_000_000,
+            3,
+        );
+        assert!(pagination_result.is_err());
+    }
+
+    /// Test [`Page`] record_fields method.
+    #[test]
+    fn test_page_model_record_fields() {
+        let page_model: Page<u32> = Page::new(&vec![5], 2, 2, 5).unwrap();
+
+        let fields: [(&str, usize); 5] = page_model.record_fields();
+        assert_eq!(
+            fields,
+            [
+                ("page", page_model.get_page()),
+                ("size", page_model.get_size()),
+                ("total", page_model.get_total()),
+                ("pages", page_model.get_pages()),
+                ("items", page_model.get_items().len()),
+            ]
+        );
+        assert_eq!(
+            fields,
+            [
+                ("page", 2),
+                ("size", 2),
+                ("total", 5),
+                ("pages", 3),
+                ("items", 1)
+            ]
+        );
+    }
+
+    /// Test [`Page`] transpose method when all items are [`Some`].
+    #[test]
+    fn test_page_model_transpose_all_some() {
+        let page_model: Page<Option<u32>> = Page::new(&vec![Some(3), Some(4)], 1, 2, 5).unwrap();
+
+        let transposed_page_model: Option<Page<u32>> = page_model.transpose();
+        assert!(transposed_page_model.is_some());
+
+        let transposed_page_model: Page<u32> = transposed_page_model.unwrap();
+        assert_eq!(transposed_page_model.get_items(), &vec![3, 4]);
+        assert_eq!(transposed_page_model.get_page(), 1);
+        assert_eq!(transposed_page_model.get_size(), 2);
+        assert_eq!(transposed_page_model.get_total(), 5);
+        assert_eq!(transposed_page_model.get_pages(), 3);
+        assert_eq!(transposed_page_model.get_previous_page(), Some(0));
+        assert_eq!(transposed_page_model.get_next_page(), Some(2));
+    }
+
+    /// Test [`Page`] transpose method when an item is [`None`].
+    #[test]
+    fn test_page_model_transpose_with_none() {
+        let page_model: Page<Option<u32>> = Page::new(&vec![Some(3), None], 1, 2, 5).unwrap();
+
+        let transposed_page_model: Option<Page<u32>> = page_model.transpose();
+        assert!(transposed_page_model.is_none());
+    }
+
+    /// Test [`Page`] transpose_result method when all items are [`Ok`].
+    #[test]
+    fn test_page_model_transpose_result_all_ok() {
+        let page_model: Page<Result<u32, String>> = Page::new(&vec![Ok(5)], 2, 2, 5).unwrap();
+
+        let transposed_page_model: Result<Page<u32>, String> = page_model.transpose_result();
+        assert!(transposed_page_model.is_ok());
+
+        let transposed_page_model: Page<u32> = transposed_page_model.unwrap();
+        assert_eq!(transposed_page_model.get_items(), &vec![5]);
+        assert_eq!(transposed_page_model.get_page(), 2);
+        assert_eq!(transposed_page_model.get_size(), 2);
+        assert_eq!(transposed_page_model.get_total(), 5);
+        assert_eq!(transposed_page_model.get_pages(), 3);
+        assert_eq!(transposed_page_model.get_previous_page(), Some(1));
+        assert_eq!(transposed_page_model.get_next_page(), None);
+    }
+
+    /// Test [`Page`] transpose_result method when items contain an [`Err`].
+    #[test]
+    fn test_page_model_transpose_result_with_err() {
+        let page_model: Page<Result<u32, String>> = Page::new(
+            &vec![
+                Ok(1),
+                Err(String::from("first")),
+                Err(String::from("second")),
+            ],
+            0,
+            3,
+            3,
+        )
+        .unwrap();
+
+        let transposed_page_model: Result<Page<u32>, String> = page_model.transpose_result();
+        assert!(transposed_page_model.is_err());
+        assert_eq!(transposed_page_model.unwrap_err(), "first");
+    }
+
+    /// Test [`Page`] dedup_by method.
+    #[test]
+    fn test_page_model_dedup_by() {
+        let mut page: Page<(u32, &str)> = Page::new(
+            &vec![(1, "a"), (1, "b"), (2, "c"), (3, "d"), (3, "e")],
+            1,
+            5,
+            12,
+        )
+        .unwrap();
+
+        page.dedup_by(|a, b| a.0 == b.0);
+
+        assert_eq!(page.get_items(), &vec![(1, "a"), (2, "c"), (3, "d")]);
+        assert_eq!(page.get_page(), 1);
+        assert_eq!(page.get_size(), 5);
+        assert_eq!(page.get_total(), 12);
+        assert_eq!(page.get_pages(), 3);
+        assert_eq!(page.get_previous_page(), Some(0));
+        assert_eq!(page.get_next_page(), Some(2));
+    }
+
+    /// Test [`Page`] page_count_for_total method.
+    #[test]
+    fn test_page_model_page_count_for_total() {
+        assert_eq!(Page::<u32>::page_count_for_total(101, 3), 34);
+        assert_eq!(Page::<u32>::page_count_for_total(99, 3), 33);
+        assert_eq!(Page::<u32>::page_count_for_total(0, 3), 1);
+        assert_eq!(Page::<u32>::page_count_for_total(10, 0), 1);
+    }
+
+    /// Test [`Page`] remaining_pages method on the first page.
+    #[test]
+    fn test_page_model_remaining_pages_first() {
+        let page: Page<u32> = Page::new(&vec![1, 2, 3], 0, 3, 101).unwrap();
+        assert_eq!(page.remaining_pages(), 33);
+    }
+
+    /// Test [`Page`] remaining_pages method on an intermediate page.
+    #[test]
+    fn test_page_model_remaining_pages_middle() {
+        let page: Page<u32> = Page::new(&vec![7, 8, 9], 2, 3, 101).unwrap();
+        assert_eq!(page.remaining_pages(), 31);
+    }
+
+    /// Test [`Page`] remaining_pages method on the last page.
+    #[test]
+    fn test_page_model_remaining_pages_last() {
+        let page: Page<u32> = Page::new(&vec![100, 101], 33, 3, 101).unwrap();
+        assert_eq!(page.remaining_pages(), 0);
+
+        let page: Page<u32> = Page::default();
+        assert_eq!(page.remaining_pages(), 0);
+    }
+
+    /// Test [`Page`] zip_with method.
+    #[test]
+    fn test_page_model_zip_with() {
+        let users: Page<&str> = Page::new(&vec!["carol", "dave"], 1, 2, 5).unwrap();
+        let scores: Page<u32> = Page::new(&vec![70, 40], 1, 2, 5).unwrap();
+
+        let scored_users: Page<(&str, u32)> =
+            users.zip_with(scores, |user, score| (user, score)).unwrap();
+
+        assert_eq!(scored_users.get_items(), &vec![("carol", 70), ("dave", 40)]);
+        assert_eq!(scored_users.get_page(), 1);
+        assert_eq!(scored_users.get_size(), 2);
+        assert_eq!(scored_users.get_total(), 5);
+        assert_eq!(scored_users.get_pages(), 3);
+        assert_eq!(scored_users.get_previous_page(), Some(0));
+        assert_eq!(scored_users.get_next_page(), Some(2));
+    }
+
+    /// Test [`Page`] zip_with method with mismatched pages.
+    #[test]
+    fn test_page_model_zip_with_mismatched_pages() {
+        let users: Page<&str> = Page::new(&vec!["carol", "dave"], 1, 2, 5).unwrap();
+        let scores: Page<u32> = Page::new(&vec![70, 40], 0, 2, 5).unwrap();
+
+        let error: PaginationError = users
+            .clone()
+            .zip_with(scores, |user, score| (user, score))
+            .unwrap_err();
+        assert!(error.get_error_kind().is_field_value_error());
+        assert_eq!(
+            error.to_string(),
+            "FIELD VALUE ERROR- Pages cannot be zipped: expected page '1', size '2' and total '5', found page '0', size '2' and total '5'"
+        );
+
+        let scores: Page<u32> = Page::new(&vec![70, 40], 1, 2, 6).unwrap();
+        assert!(users
+            .clone()
+            .zip_with(scores, |user, score| (user, score))
+            .is_err());
+
+        let last_users: Page<&str> = Page::new(&vec!["erin"], 2, 2, 5).unwrap();
+        let last_scores: Page<u32> = Page::new(&vec![10], 2, 2, 5).unwrap();
+        assert!(last_users
+            .zip_with(last_scores, |user, score| (user, score))
+            .is_ok());
+    }
+
+    /// Test [`Page`] to_canonical_json method.
+    #[cfg(feature = "serde")]
+    #[cfg(not(feature = "serde-camel-case"))]
+    #[test]
+    fn test_page_model_to_canonical_json() {
+        let page: Page<u32> = Page::new(&vec![3, 4], 1, 2, 5).unwrap();
+
+        let first_json: String = page.to_canonical_json().unwrap();
+        let second_json: String = page.clone().to_canonical_json().unwrap();
+
+        assert_eq!(first_json.as_bytes(), second_json.as_bytes());
+        assert_eq!(
+            first_json,
+            r#"{"items":[3,4],"page":1,"size":2,"total":5,"pages":3,"previous_page":0,"next_page":2}"#
+        );
+    }
+
+    /// Test [`Page`] items_mut method.
+    #[test]
+    fn test_page_model_items_mut() {
+        let mut page: Page<String> = Page::new(
+            &vec![
+                String::from("alice@example.com"),
+                String::from("bob@example.com"),
+            ],
+            1,
+            2,
+            5,
+        )
+        .unwrap();
+        let metadata: PageMetadata = page.metadata();
+
+        page.items_mut()[0] = String::from("<redacted>");
+        for item in page.items_mut().iter_mut().skip(1) {
+            item.make_ascii_uppercase();
+        }
+
+        assert_eq!(
+            page.as_slice(),
+            &[String::from("<redacted>"), String::from("BOB@EXAMPLE.COM")]
+        );
+        assert_eq!(page.metadata(), metadata);
+        assert!(page.verify_fields().is_ok());
+    }
+
+    /// Test [`Page`] get_items_mut method.
+    #[test]
+    fn test_page_model_get_items_mut() {
+        #[derive(Clone, Debug, PartialEq)]
+        struct User {
+            name: String,
+            email: String,
+        }
+
+        let mut page: Page<User> = Page::new(
+            &vec![
+                User {
+                    name: String::from("alice"),
+                    email: String::from("alice@mail.com"),
+                },
+                User {
+                    name: String::from("bob"),
+                    email: String::from("bob@mail.com"),
+                },
+            ],
+            0,
+            2,
+            3,
+        )
+        .unwrap();
+
+        for user in page.get_items_mut() {
+            user.email = String::from("***");
+        }
+
+        assert_eq!(page.get_items()[0].email, "***");
+        assert_eq!(page.get_items()[1].email, "***");
+        assert_eq!(page.get_items()[1].name, "bob");
+        assert!(page.verify_fields().is_ok());
+
+        page.get_items_mut().pop();
+        assert!(page.verify_fields().is_err());
+    }
+
+    /// Test [`Page`] into_book method.
+    #[test]
+    fn test_page_model_into_book() {
+        let page: Page<u32> = Page::new(&vec![3, 4], 1, 2, 5).unwrap();
+
+        let book: Book<u32> = page.into_book();
+
+        assert_eq!(book.get_sheets().len(), 1);
+        assert_eq!(book.get_sheets()[0].get_items(), &vec![3, 4]);
+        assert_eq!(book.get_sheets()[0].get_page(), 1);
+        assert_eq!(book.get_sheets()[0].get_size(), 2);
+        assert_eq!(book.get_sheets()[0].get_total(), 5);
+    }
+
+    /// Test [`Page`] to_bytes and from_bytes methods.
+    #[cfg(feature = "bincode")]
+    #[test]
+    fn test_page_model_to_bytes_and_from_bytes() {
+        let page: Page<u32> = Page::new(&vec![3, 4], 1, 2, 5).unwrap();
+
+        let bytes: Vec<u8> = page.to_bytes().unwrap();
+        let decoded_page: Page<u32> = Page::from_bytes(&bytes).unwrap();
+
+        assert_eq!(decoded_page.get_items(), page.get_items());
+        assert_eq!(decoded_page.get_page(), page.get_page());
+        assert_eq!(decoded_page.get_size(), page.get_size());
+        assert_eq!(decoded_page.get_total(), page.get_total());
+        assert_eq!(decoded_page.get_pages(), page.get_pages());
+        assert_eq!(decoded_page.get_previous_page(), page.get_previous_page());
+        assert_eq!(decoded_page.get_next_page(), page.get_next_page());
+    }
+
+    /// Test [`Page`] to_bytes and from_bytes methods on the first and last pages, which have no previous or next page.
+    #[cfg(feature = "bincode")]
+    #[test]
+    fn test_page_model_to_bytes_and_from_bytes_without_links() {
+        let first_page: Page<u32> = Page::new(&vec![1, 2], 0, 2, 5).unwrap();
+        let decoded_page: Page<u32> = Page::from_bytes(&first_page.to_bytes().unwrap()).unwrap();
+        assert_eq!(decoded_page, first_page);
+
+        let last_page: Page<u32> = Page::new(&vec![5], 2, 2, 5).unwrap();
+        let decoded_page: Page<u32> = Page::from_bytes(&last_page.to_bytes().unwrap()).unwrap();
+        assert_eq!(decoded_page, last_page);
+
+        let single_page: Page<u32> = Page::new(&vec![1, 2], 0, 2, 2).unwrap();
+        let decoded_page: Page<u32> = Page::from_bytes(&single_page.to_bytes().unwrap()).unwrap();
+        assert_eq!(decoded_page, single_page);
+    }
+
+    /// Test [`Page`] from_bytes method with a corrupted payload.
+    #[cfg(feature = "bincode")]
+    #[test]
+    fn test_page_model_from_bytes_corrupted() {
+        let page: Page<u32> = Page::new(&vec![3, 4], 1, 2, 5).unwrap();
+
+        // Items are encoded as an u64 length followed by two u32 values, so the page index starts at byte 16.
+        let mut bytes: Vec<u8> = page.to_bytes().unwrap();
+        bytes[16] = 9;
+
+        let decoded_page: Result<Page<u32>, bincode::Error> = Page::from_bytes(&bytes);
+        assert!(decoded_page.is_err());
+        assert_eq!(
+            decoded_page.unwrap_err().to_string(),
+            "OUT OF RANGE PAGE ERROR- Page index '9' exceeds total pages '3'"
+        );
+
+        let truncated_page: Result<Page<u32>, bincode::Error> = Page::from_bytes(&bytes[..10]);
+        assert!(truncated_page.is_err());
+    }
+
+    /// Test [`Page`] new_lenient method with valid fields.
+    #[test]
+    fn test_page_model_new_lenient_valid() {
+        let (page, adjusted): (Page<u32>, bool) =
+            Page::new_lenient(&vec![13, 14, 15], 3, 4, 15).unwrap();
+
+        assert!(!adjusted);
+        assert_eq!(page.get_total(), 15);
+        assert_eq!(page.get_pages(), 4);
+    }
+
+    /// Test [`Page`] new_lenient method with a slightly off total on the last page.
+    #[test]
+    fn test_page_model_new_lenient_adjusted() {
+        let (page, adjusted): (Page<u32>, bool) =
+            Page::new_lenient(&vec![13, 14, 15], 3, 4, 16).unwrap();
+
+        assert!(adjusted);
+        assert_eq!(page.get_items(), &vec![13, 14, 15]);
+        assert_eq!(page.get_page(), 3);
+        assert_eq!(page.get_size(), 4);
+        assert_eq!(page.get_total(), 15);
+        assert_eq!(page.get_pages(), 4);
+        assert_eq!(page.get_previous_page(), Some(2));
+        assert_eq!(page.get_next_page(), None);
+
+        let (page, adjusted): (Page<u32>, bool) =
+            Page::new_lenient(&vec![13, 14, 15, 16], 3, 4, 14).unwrap();
+
+        assert!(adjusted);
+        assert_eq!(page.get_total(), 16);
+    }
+
+    /// Test [`Page`] new_lenient method with wildly inconsistent fields.
+    #[test]
+    fn test_page_model_new_lenient_error() {
+        let pagination_result: PaginationResult<(Page<u32>, bool)> =
+            Page::new_lenient(&vec![1], 0, 4, 100);
+        assert!(pagination_result.is_err());
+        assert_eq!(
+            pagination_result.unwrap_err().to_string(),
+            "FIELD VALUE ERROR- Items length '1' is not equal to page size '4' for an intermediate page '0'"
+        );
+
+        let pagination_result: PaginationResult<(Page<u32>, bool)> =
+            Page::new_lenient(&vec![13, 14, 15], 3, 4, 20);
+        assert!(pagination_result.is_err());
+
+        let pagination_result: PaginationResult<(Page<u32>, bool)> =
+            Page::new_lenient(&vec![13, 14, 15, 16, 17], 3, 4, 17);
+        assert!(pagination_result.is_err());
+
+        // A huge page index is rejected without overflowing the adjusted total.
+        let pagination_result: PaginationResult<(Page<u32>, bool)> =
+            Page::new_lenient(&vec![], usize::MAX, 2, 0);
+        assert!(pagination_result.is_err());
+
+        let pagination_result: PaginationResult<(Page<u32>, bool)> =
+            Page::new_lenient(&vec![1, 2], usize::MAX / 2, 2, usize::MAX);
+        assert!(pagination_result.is_err());
+    }
+
+    /// Test [`Page`] get method.
+    #[test]
+    fn test_page_model_get() {
+        let page: Page<u32> = Page::new(&vec![3, 4], 1, 2, 5).unwrap();
+
+        assert_eq!(page.get(0), Some(&3));
+        assert_eq!(page.get(1), Some(&4));
+        assert_eq!(page.get(2), None);
+    }
+
+    /// Test [`Page`] checked_map method.
+    #[test]
+    fn test_page_model_checked_map() {
+        let page: Page<u32> = Page::new(&vec![3, 4], 1, 2, 5).unwrap();
+
+        let mapped_page: Page<String> = page.checked_map(|item| item.to_string()).unwrap();
+
+        assert_eq!(
+            mapped_page.get_items(),
+            &vec![String::from("3"), String::from("4")]
+        );
+        assert_eq!(mapped_page.get_page(), 1);
+        assert_eq!(mapped_page.get_size(), 2);
+        assert_eq!(mapped_page.get_total(), 5);
+        assert_eq!(mapped_page.get_pages(), 3);
+        assert_eq!(mapped_page.get_previous_page(), Some(0));
+        assert_eq!(mapped_page.get_next_page(), Some(2));
+
+        let last_page: Page<u32> = Page::new(&vec![5], 2, 2, 5).unwrap();
+        let mapped_last_page: Page<u32> = last_page.checked_map(|item| item * 10).unwrap();
+        assert_eq!(mapped_last_page.get_items(), &vec![50]);
+    }
+
+    /// Test [`Page`] empty method.
+    #[test]
+    fn test_page_model_empty() {
+        let page: Page<u32> = Page::empty(10);
+
+        assert!(page.get_items().is_empty());
+        assert_eq!(page.get_page(), 0);
+        assert_eq!(page.get_size(), 10);
+        assert_eq!(page.get_total(), 0);
+        assert_eq!(page.get_pages(), 1);
+        assert_eq!(page.get_previous_page(), None);
+        assert_eq!(page.get_next_page(), None);
+        assert!(page.verify_fields().is_ok());
+    }
+
+    /// Test [`PaginationResultExt`] or_empty_page method with a page out of range.
+    #[test]
+    fn test_pagination_result_or_empty_page_out_of_range() {
+        let records: Vec<u32> = vec![1, 2, 3, 4, 5];
+
+        let page: Page<u32> = paginate_records(&records, 10, 2).or_empty_page(2).unwrap();
+        assert!(page.get_items().is_empty());
+        assert_eq!(page.get_size(), 2);
+        assert_eq!(page.get_total(), 0);
+
+        let page: Page<u32> = paginate_records(&records, 10, 2).unwrap_or_empty_page(2);
+        assert!(page.get_items().is_empty());
+
+        let page: Page<u32> = paginate_records(&records, 1, 2).unwrap_or_empty_page(2);
+        assert_eq!(page.get_items(), &vec![3, 4]);
+    }
+
+    /// Test [`PaginationResultExt`] or_empty_page method preserving other errors.
+    #[test]
+    fn test_pagination_result_or_empty_page_other_error() {
+        let pagination_result: PaginationResult<Page<u32>> =
+            Page::new(&vec![1], 0, 2, 5).or_empty_page(2);
+        assert!(pagination_result.is_err());
+        assert_eq!(
+            pagination_result.unwrap_err().to_string(),
+            "FIELD VALUE ERROR- Items length '1' is not equal to page size '2' for an intermediate page '0'"
+        );
+    }
+
+    /// Test [`PaginationResultExt`] unwrap_or_empty_page method panicking on other errors.
+    #[test]
+    #[should_panic(expected = "Items length '1' is not equal to page size '2'")]
+    fn test_pagination_result_unwrap_or_empty_page_panic() {
+        Page::new(&vec![1], 0, 2, 5).unwrap_or_empty_page(2);
+    }
+
+    /// Test [`Page`] map_indexed method.
+    #[test]
+    fn test_page_model_map_indexed() {
+        let page: Page<&str> = Page::new(&vec!["erin", "frank", "grace"], 2, 3, 10).unwrap();
+
+        let ranked_page: Page<(usize, &str)> = page.map_indexed(|index, name| (index, name));
+
+        assert_eq!(
+            ranked_page.get_items(),
+            &vec![(6, "erin"), (7, "frank"), (8, "grace")]
+        );
+        assert_eq!(ranked_page.get_page(), 2);
+        assert_eq!(ranked_page.get_size(), 3);
+        assert_eq!(ranked_page.get_total(), 10);
+        assert_eq!(ranked_page.get_pages(), 4);
+        assert_eq!(ranked_page.get_previous_page(), Some(1));
+        assert_eq!(ranked_page.get_next_page(), Some(3));
+    }
+
+    /// Test [`Page`] map_indexed method on an empty page beyond the last page with a huge index.
+    #[test]
+    fn test_page_model_map_indexed_beyond_range() {
+        let records: Vec<u32> = vec![1, 2, 3, 4, 5];
+        let page: Page<u32> = paginate_records_clamped(&records, usize::MAX, 2).unwrap();
+        assert!(page.verify_fields().is_ok());
+
+        let ranked_page: Page<(usize, u32)> = page.map_indexed(|index, record| (index, record));
+        assert!(ranked_page.get_items().is_empty());
+        assert_eq!(ranked_page.get_page(), usize::MAX);
+        assert_eq!(ranked_page.get_previous_page(), Some(2));
+        assert_eq!(ranked_page.get_next_page(), None);
+    }
+
+    /// Test [`Page`] from_iter method.
+    #[test]
+    fn test_page_model_from_iter() {
+        let page: Page<u32> = (1..=5).collect();
+
+        assert_eq!(page.get_items(), &vec![1, 2, 3, 4, 5]);
+        assert_eq!(page.get_page(), 0);
+        assert_eq!(page.get_size(), 5);
+        assert_eq!(page.get_total(), 5);
+        assert_eq!(page.get_pages(), 1);
+        assert_eq!(page.get_previous_page(), None);
+        assert_eq!(page.get_next_page(), None);
+        assert!(page.verify_fields().is_ok());
+
+        let empty_page: Page<u32> = std::iter::empty().collect();
+        assert_eq!(empty_page.get_total(), 0);
+        assert_eq!(empty_page.get_pages(), 1);
+        assert!(empty_page.verify_fields().is_ok());
+    }
+
+    /// Test [`Page`] neighbors method on the first page.
+    #[test]
+    fn test_page_model_neighbors_first() {
+        let page: Page<u32> = Page::new(&vec![1, 2], 0, 2, 5).unwrap();
+        assert_eq!(page.neighbors(), (None, Some((1, 2))));
+    }
+
+    /// Test [`Page`] neighbors method on an intermediate page.
+    #[test]
+    fn test_page_model_neighbors_middle() {
+        let page: Page<u32> = Page::new(&vec![3, 4], 1, 2, 5).unwrap();
+        assert_eq!(page.neighbors(), (Some((0, 2)), Some((2, 2))));
+    }
+
+    /// Test [`Page`] neighbors method on the last page.
+    #[test]
+    fn test_page_model_neighbors_last() {
+        let page: Page<u32> = Page::new(&vec![5], 2, 2, 5).unwrap();
+        assert_eq!(page.neighbors(), (Some((1, 2)), None));
+
+        let single_page: Page<u32> = Page::new(&vec![1, 2], 0, 2, 2).unwrap();
+        assert_eq!(single_page.neighbors(), (None, None));
+    }
+
+    /// Test [`Page`] from_results method with successful items.
+    #[test]
+    fn test_page_model_from_results() {
+        let results: Vec<Result<u32, PaginationError>> = vec![Ok(3), Ok(4)];
+
+        let page: Page<u32> = Page::from_results(results, 1, 2, 5).unwrap();
+
+        assert_eq!(page.get_items(), &vec![3, 4]);
+        assert_eq!(page.get_page(), 1);
+        assert_eq!(page.get_size(), 2);
+        assert_eq!(page.get_total(), 5);
+        assert_eq!(page.get_pages(), 3);
+        assert_eq!(page.get_previous_page(), Some(0));
+        assert_eq!(page.get_next_page(), Some(2));
+
+        let results: Vec<Result<u32, PaginationError>> = vec![Ok(3)];
+        assert!(Page::from_results(results, 1, 2, 5).is_err());
+    }
+
+    /// Test [`Page`] from_results method with an error item.
+    #[test]
+    fn test_page_model_from_results_error() {
+        #[derive(Debug)]
+        enum RowError {
+            Decode(String),
+            Pagination(String),
+        }
+
+        impl From<PaginationError> for RowError {
+            fn from(error: PaginationError) -> Self {
+                RowError::Pagination(error.to_string())
+            }
+        }
+
+        let mut consumed: usize = 0;
+        let results = vec!["3", "x", "5"].into_iter().map(|row| {
+            consumed += 1;
+            row.parse::<u32>()
+                .map_err(|error| RowError::Decode(error.to_string()))
+        });
+
+        let pagination_result: Result<Page<u32>, RowError> = Page::from_results(results, 1, 2, 5);
+        match pagination_result.unwrap_err() {
+            RowError::Decode(detail) => assert_eq!(detail, "invalid digit found in string"),
+            RowError::Pagination(detail) => panic!("Unexpected pagination error: {}", detail),
+        }
+        assert_eq!(consumed, 2);
+    }
+
+    /// Test [`Page`] deserialization of borrowed string items.
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_page_model_deserialization_borrowed_items() {
+        use serde::Deserialize;
+        use std::borrow::Cow;
+
+        #[derive(Deserialize)]
+        struct Name<'a>(#[serde(borrow)] Cow<'a, str>);
+
+        let json: String = String::from(
+            r#"{"items":["alice","b\u00f6b"],"page":0,"size":2,"total":5,"pages":3,"previous_page":null,"next_page":1}"#,
+        );
+
+        let cow_page: Page<Cow<str>> = serde_json::from_str(&json).unwrap();
+        assert_eq!(cow_page.get_items(), &vec!["alice", "böb"]);
+
+        let name_page: Page<Name> = serde_json::from_str(&json).unwrap();
+        assert!(matches!(name_page.get_items()[0].0, Cow::Borrowed("alice")));
+        assert!(matches!(&name_page.get_items()[1].0, Cow::Owned(name) if name == "böb"));
+
+        let str_page_result: Result<Page<&str>, serde_json::Error> = serde_json::from_str(&json);
+        assert!(str_page_result.is_err());
+
+        let unescaped_json: String = json.replace(r#"b\u00f6b"#, "bob");
+        let str_page: Page<&str> = serde_json::from_str(&unescaped_json).unwrap();
+        assert_eq!(str_page.get_items(), &vec!["alice", "bob"]);
+        assert!(unescaped_json
+            .as_bytes()
+            .as_ptr_range()
+            .contains(&str_page.get_items()[0].as_ptr()));
+    }
+
+    /// Test [`Page`] deserialization of borrowed string items with invalid fields.
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_page_model_deserialization_borrowed_items_error() {
+        use std::borrow::Cow;
+
+        let json: &str = r#"{"items":["alice","bob"],"page":0,"size":2,"total":5,"pages":3,"previous_page":null,"next_page":2}"#;
+
+        let cow_page_result: Result<Page<Cow<str>>, serde_json::Error> = serde_json::from_str(json);
+        assert!(cow_page_result.is_err());
+        assert_eq!(
+            cow_page_result.unwrap_err().to_string(),
+            "FIELD VALUE ERROR- Next page index error: expected 'Some(1)', found 'Some(2)'"
+        );
+
+        let str_page_result: Result<Page<&str>, serde_json::Error> = serde_json::from_str(json);
+        assert!(str_page_result.is_err());
+    }
+
+    /// Test [`Page`] heap_size method.
+    #[cfg(feature = "deepsize")]
+    #[test]
+    fn test_page_model_heap_size() {
+        let bytes_page: Page<u8> = Page::new(&vec![1, 2], 0, 2, 5).unwrap();
+        let strings_page: Page<String> = Page::new(
+            &vec![String::from("first record"), String::from("second record")],
+            0,
+            2,
+            5,
+        )
+        .unwrap();
+
+        assert!(bytes_page.heap_size() >= std::mem::size_of::<Page<u8>>() + 2);
+        assert!(strings_page.heap_size() > bytes_page.heap_size());
+        assert!(
+            strings_page.heap_size()
+                >= std::mem::size_of::<Page<String>>()
+                    + 2 * std::mem::size_of::<String>()
+                    + "first record".len()
+                    + "second record".len()
+        );
+    }
+
+    /// Test [`Page`] sample method.
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_page_model_sample() {
+        use rand::{rngs::StdRng, SeedableRng};
+
+        let page: Page<u32> = Page::new(&vec![11, 12, 13, 14, 15], 2, 5, 20).unwrap();
+
+        let sample: Vec<u32> = page.sample(3, &mut StdRng::seed_from_u64(42));
+        let same_seed_sample: Vec<u32> = page.sample(3, &mut StdRng::seed_from_u64(42));
+        assert_eq!(sample, same_seed_sample);
+
+        assert_eq!(sample.len(), 3);
+        assert!(sample.iter().all(|item| page.get_items().contains(item)));
+        let mut distinct_sample: Vec<u32> = sample.clone();
+        distinct_sample.sort();
+        distinct_sample.dedup();
+        assert_eq!(distinct_sample.len(), 3);
+
+        assert_eq!(page.sample(10, &mut StdRng::seed_from_u64(42)).len(), 5);
+        assert_eq!(page.get_items(), &vec![11, 12, 13, 14, 15]);
+    }
+
+    /// Test [`Page`] eq method.
+    #[test]
+    fn test_page_model_eq() {
+        let page: Page<u32> = Page::new(&vec![3, 4], 1, 2, 5).unwrap();
+
+        assert_eq!(page, Page::new(&vec![3, 4], 1, 2, 5).unwrap());
+        assert_ne!(page, Page::new(&vec![4, 3], 1, 2, 5).unwrap());
+        assert_ne!(page, Page::new(&vec![3, 4], 1, 2, 6).unwrap());
+    }
+
+    /// Test [`Page`] ordering by page index.
+    #[test]
+    fn test_page_model_ord() {
+        let records: Vec<u32> = (1..=9).collect();
+        let mut pages: Vec<Page<u32>> = vec![
+            paginate_records(&records, 3, 2).unwrap(),
+            paginate_records(&records, 0, 2).unwrap(),
+            paginate_records(&records, 4, 2).unwrap(),
+            paginate_records(&records, 2, 2).unwrap(),
+            paginate_records(&records, 1, 2).unwrap(),
+        ];
+
+        pages.sort();
+
+        assert_eq!(
+            pages
+                .iter()
+                .map(|page| page.get_page())
+                .collect::<Vec<usize>>(),
+            vec![0, 1, 2, 3, 4]
+        );
+        assert!(pages[0] < pages[1]);
+        assert_eq!(
+            Page::new(&vec![1, 2], 0, 2, 4)
+                .unwrap()
+                .cmp(&Page::new(&vec![3, 4], 0, 2, 5).unwrap()),
+            std::cmp::Ordering::Equal
+        );
+
+        #[derive(Clone, Debug, PartialEq, Eq)]
+        struct Unordered(u32);
+
+        let records: Vec<Unordered> = (1..=5).map(Unordered).collect();
+        let mut pages: Vec<Page<Unordered>> = vec![
+            paginate_records(&records, 2, 2).unwrap(),
+            paginate_records(&records, 0, 2).unwrap(),
+            paginate_records(&records, 1, 2).unwrap(),
+        ];
+
+        pages.sort();
+
+        assert_eq!(
+            pages
+                .iter()
+                .map(|page| page.get_page())
+                .collect::<Vec<usize>>(),
+            vec![0, 1, 2]
+        );
+    }
+
+    /// Test [`Page`] with_items method.
+    #[test]
+    fn test_page_model_with_items() {
+        let page: Page<u32> = Page::new(&vec![3, 4], 1, 2, 5).unwrap();
+
+        let replaced_page: Page<u32> = page.clone().with_items(vec![30, 40]).unwrap();
+        assert_eq!(replaced_page.get_items(), &vec![30, 40]);
+        assert_eq!(replaced_page.get_page(), 1);
+        assert_eq!(replaced_page.get_size(), 2);
+        assert_eq!(replaced_page.get_total(), 5);
+        assert_eq!(replaced_page.get_pages(), 3);
+        assert_eq!(replaced_page.get_previous_page(), Some(0));
+        assert_eq!(replaced_page.get_next_page(), Some(2));
+
+        let error: PaginationError = page.with_items(vec![30]).unwrap_err();
+        assert!(error.get_error_kind().is_field_value_error());
+    }
+
+    /// Test [`Page`] total_as method.
+    #[test]
+    fn test_page_model_total_as() {
+        let page: Page<u32> = Page::new(&vec![1, 2], 0, 2, 5).unwrap();
+        assert_eq!(page.total_as::<u32>(), Ok(5));
+        assert_eq!(page.total_as::<u8>(), Ok(5));
+
+        let large_page: Page<u32> = Page::new(&vec![1, 2], 0, 2, 300).unwrap();
+        assert_eq!(large_page.total_as::<u32>(), Ok(300));
+        assert!(large_page.total_as::<u8>().is_err());
+    }
+
+    /// Test [`Page`] as_ref_page method.
+    #[test]
+    fn test_page_model_as_ref_page() {
+        let page: Page<String> =
+            Page::new(&vec![String::from("c"), String::from("d")], 1, 2, 5).unwrap();
+
+        let ref_page: Page<&String> = page.as_ref_page();
+        assert_eq!(
+            ref_page.get_items(),
+            &vec![&page.get_items()[0], &page.get_items()[1]]
+        );
+        assert_eq!(ref_page.get(1).map(|item| item.as_str()), Some("d"));
+        assert_eq!(ref_page.get_page(), page.get_page());
+        assert_eq!(ref_page.get_size(), page.get_size());
+        assert_eq!(ref_page.get_total(), page.get_total());
+        assert_eq!(ref_page.get_pages(), page.get_pages());
+        assert_eq!(ref_page.get_previous_page(), page.get_previous_page());
+        assert_eq!(ref_page.get_next_page(), page.get_next_page());
+        assert!(ref_page.verify_fields().is_ok());
+    }
+
+    /// Test [`deserialize_page_or_array`] function with a paginated object and a bare array.
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_deserialize_page_or_array() {
+        use serde::Deserialize;
+
+        #[derive(Debug, Deserialize)]
+        struct SearchResponse {
+            #[serde(deserialize_with = "deserialize_page_or_array")]
+            results: Page<u32>,
+        }
+
+        let response: SearchResponse = serde_json::from_str(
+            r#"{"results":{"items":[3,4],"page":1,"size":2,"total":5,"pages":3,"previous_page":0,"next_page":2}}"#,
+        )
+        .unwrap();
+        assert_eq!(response.results, Page::new(&vec![3, 4], 1, 2, 5).unwrap());
+
+        let response: SearchResponse = serde_json::from_str(r#"{"results":[1,2,3]}"#).unwrap();
+        assert_eq!(response.results.get_items(), &vec![1, 2, 3]);
+        assert_eq!(response.results.get_page(), 0);
+        assert_eq!(response.results.get_size(), 3);
+        assert_eq!(response.results.get_total(), 3);
+        assert_eq!(response.results.get_pages(), 1);
+        assert_eq!(response.results.get_previous_page(), None);
+        assert_eq!(response.results.get_next_page(), None);
+
+        let invalid_page: Result<SearchResponse, serde_json::Error> = serde_json::from_str(
+            r#"{"results":{"items":[3],"page":1,"size":2,"total":5,"pages":3,"previous_page":0,"next_page":2}}"#,
+        );
+        assert!(invalid_page.is_err());
+
+        let invalid_shape: Result<SearchResponse, serde_json::Error> =
+            serde_json::from_str(r#"{"results":3}"#);
+        assert!(invalid_shape
+            .unwrap_err()
+            .to_string()
+            .contains("a page object or an array of items"));
+    }
+
+    /// Test [`Page`] is_valid method.
+    #[test]
+    fn test_page_model_is_valid() {
+        let mut page: Page<u32> = Page::new(&vec![3, 4], 1, 2, 5).unwrap();
+        assert!(page.is_valid());
+        assert!(Page::<u32>::default().is_valid());
+
+        page.get_items_mut().pop();
+        assert!(!page.is_valid());
+    }
+
+    /// Test [`Page`] new_circular method.
+    #[test]
+    fn test_page_model_new_circular() {
+        let first_page: Page<u32> = Page::new_circular(&vec![1, 2], 0, 2, 5).unwrap();
+        assert_eq!(first_page.get_previous_page(), Some(2));
+        assert_eq!(first_page.get_next_page(), Some(1));
+        assert_eq!(first_page.neighbors(), (Some((2, 2)), Some((1, 2))));
+
+        let middle_page: Page<u32> = Page::new_circular(&vec![3, 4], 1, 2, 5).unwrap();
+        assert_eq!(middle_page, Page::new(&vec![3, 4], 1, 2, 5).unwrap());
+
+        let last_page: Page<u32> = Page::new_circular(&vec![5], 2, 2, 5).unwrap();
+        assert_eq!(last_page.get_previous_page(), Some(1));
+        assert_eq!(last_page.get_next_page(), Some(0));
+
+        let single_page: Page<u32> = Page::new_circular(&vec![1, 2], 0, 2, 2).unwrap();
+        assert_eq!(single_page.get_previous_page(), None);
+        assert_eq!(single_page.get_next_page(), None);
+
+        for page in [&first_page, &middle_page, &last_page, &single_page] {
+            assert!(page.verify_fields_circular().is_ok());
+        }
+        assert!(first_page.verify_fields().is_err());
+        assert!(last_page.verify_fields().is_err());
+        assert!(single_page.verify_fields().is_ok());
+
+        assert!(Page::new(&vec![1, 2], 0, 2, 5)
+            .unwrap()
+            .verify_fields_circular()
+            .is_err());
+        assert!(Page::new_circular(&vec![1], 0, 2, 5).is_err());
+    }
+
+    /// Test [`Page`] items_owned method.
+    #[test]
+    fn test_page_model_items_owned() {
+        let page: Page<String> =
+            Page::new(&vec![String::from("a"), String::from("b")], 0, 2, 5).unwrap();
+        let borrowed_page: &Page<String> = &page;
+
+        let mut items: Vec<String> = borrowed_page.items_owned();
+        items.push(String::from("c"));
+        assert_eq!(items, vec!["a", "b", "c"]);
+
+        assert_eq!(page.get_items(), &vec!["a", "b"]);
+        assert!(page.is_valid());
+    }
+
+    /// Test [`Page`] last_page_is_full method.
+    #[test]
+    fn test_page_model_last_page_is_full() {
+        let full_last_page: Page<u32> = Page::new(&vec![3, 4], 1, 2, 4).unwrap();
+        assert!(full_last_page.last_page_is_full());
+
+        let partial_last_page: Page<u32> = Page::new(&vec![5], 2, 2, 5).unwrap();
+        assert!(!partial_last_page.last_page_is_full());
+
+        let intermediate_page: Page<u32> = Page::new(&vec![1, 2], 0, 2, 4).unwrap();
+        assert!(!intermediate_page.last_page_is_full());
+
+        let empty_page: Page<u32> = Page::empty(2);
+        assert!(!empty_page.last_page_is_full());
+
+        let circular_last_page: Page<u32> = Page::new_circular(&vec![3, 4], 1, 2, 4).unwrap();
+        assert_eq!(circular_last_page.get_next_page(), Some(0));
+        assert!(circular_last_page.last_page_is_full());
+    }
+
+    /// Test camelCase serialization and deserialization of [`Page`].
+    #[cfg(feature = "serde-camel-case")]
+    #[test]
+    fn test_page_model_serde_camel_case() {
+        let page: Page<u32> = Page::new(&vec![3, 4], 1, 2, 5).unwrap();
+
+        let serialized: String = serde_json::to_string(&page).unwrap();
+        assert_eq!(
+            serialized,
+            r#"{"items":[3,4],"page":1,"size":2,"total":5,"pages":3,"previousPage":0,"nextPage":2}"#
+        );
+
+        let deserialized: Page<u32> = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(deserialized, page);
+
+        // The snake_case names are still accepted on deserialization.
+        let deserialized: Page<u32> = serde_json::from_str(
+            r#"{"items":[3,4],"page":1,"size":2,"total":5,"pages":3,"previous_page":0,"next_page":2}"#,
+        )
+        .unwrap();
+        assert_eq!(deserialized, page);
+    }
+
+    /// Test [`Page`] metadata method.
+    #[test]
+    fn test_page_model_metadata() {
+        let page: Page<u32> = Page::new(&vec![3, 4], 1, 2, 5).unwrap();
+
+        let metadata: PageMetadata = page.metadata();
+        assert_eq!(metadata.get_page(), 1);
+        assert_eq!(metadata.get_size(), 2);
+        assert_eq!(metadata.get_total(), 5);
+        assert_eq!(metadata.get_pages(), 3);
+        assert_eq!(metadata.get_previous_page(), Some(0));
+        assert_eq!(metadata.get_next_page(), Some(2));
+
+        let empty: Page<u32> = Page::default();
+        assert_eq!(empty.metadata().get_pages(), 1);
+        assert_eq!(empty.metadata().get_previous_page(), None);
+        assert_eq!(empty.metadata().get_next_page(), None);
+    }
+
+    /// Test serialization and deserialization of [`PageMetadata`].
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_page_metadata_serialization() {
+        let page: Page<u32> = Page::new(&vec![3, 4], 1, 2, 5).unwrap();
+
+        let json: serde_json::Value = serde_json::to_value(page.metadata()).unwrap();
+        assert!(json.get("items").is_none());
+        assert_eq!(json["page"], 1);
+        assert_eq!(json["total"], 5);
+        assert_eq!(json["pages"], 3);
+
+        let deserialized: PageMetadata = serde_json::from_value(json).unwrap();
+        assert_eq!(deserialized, page.metadata());
+    }
+
+    /// Test [`Page`] split_off_items method and [`PageMetadata`] attach method.
+    #[test]
+    fn test_page_model_split_off_items_and_attach() {
+        let page: Page<u32> = Page::new(&vec![3, 4], 1, 2, 5).unwrap();
+
+        let (items, metadata): (Vec<u32>, PageMetadata) = page.clone().split_off_items();
+        assert_eq!(items, vec![3, 4]);
+        assert_eq!(metadata, page.metadata());
+
+        let labels: Vec<String> = items.iter().map(|item| format!("#{}", item)).collect();
+        let labeled_page: Page<String> = metadata.attach(labels).unwrap();
+        assert_eq!(
+            labeled_page.get_items(),
+            &vec![String::from("#3"), String::from("#4")]
+        );
+        assert_eq!(labeled_page.metadata(), metadata);
+
+        let round_trip: Page<u32> = metadata.attach(items).unwrap();
+        assert_eq!(round_trip, page);
+
+        let error: PaginationError = metadata.attach(vec![3, 4, 5]).unwrap_err();
+        assert!(error.get_error_kind().is_field_value_error());
+    }
+
+    /// Test [`Page`] new_unchecked method.
+    #[test]
+    fn test_page_model_new_unchecked() {
+        let page: Page<u32> = Page::new_unchecked(vec![3, 4], 1, 2, 5, 3, Some(0), Some(2));
+        assert_eq!(page, Page::new(&vec![3, 4], 1, 2, 5).unwrap());
+        assert!(page.verify_fields().is_ok());
+
+        // The fields are kept as given, even if they are inconsistent.
+        let inconsistent: Page<u32> = Page::new_unchecked(vec![3, 4], 1, 2, 5, 4, None, None);
+        assert_eq!(inconsistent.get_pages(), 4);
+        assert_eq!(inconsistent.get_previous_page(), None);
+        assert!(inconsistent.verify_fields().is_err());
+    }
+
+    /// Test [`Page`] verify_fields method with an empty page beyond the last page.
+    #[test]
+    fn test_page_model_verify_fields_beyond_range() {
+        let page: Page<u32> = Page::new_unchecked(vec![], 12, 10, 100, 10, Some(9), None);
+        assert!(page.verify_fields().is_ok());
+        assert!(page
+            .verify_fields_circular()
+            .unwrap_err()
+            .get_error_kind()
+            .is_out_of_range_page());
+        assert_eq!(page.remaining_pages(), 0);
+
+        // Items or other links beyond the last page are still out of range.
+        let with_items: Page<u32> = Page::new_unchecked(vec![1], 12, 10, 100, 10, Some(9), None);
+        assert!(with_items
+            .verify_fields()
+            .unwrap_err()
+            .get_error_kind()
+            .is_out_of_range_page());
+
+        let wrong_link: Page<u32> = Page::new_unchecked(vec![], 12, 10, 100, 10, Some(11), None);
+        assert!(wrong_link.verify_fields().is_err());
+        assert!(Page::<u32>::new(&vec![], 12, 10, 100).is_err());
+    }
+
+    /// Test serialization and deserialization of an empty [`Page`] beyond the last page.
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_page_model_beyond_range_serde() {
+        let page: Page<u32> = Page::new_unchecked(vec![], 12, 10, 100, 10, Some(9), None);
+
+        let serialized: String = serde_json::to_string(&page).unwrap();
+        let deserialized: Page<u32> = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(deserialized, page);
+
+        let huge_page: Page<u32> = serde_json::from_str(
+            r#"{"items":[],"page":18446744073709551615,"size":2,"total":5,"pages":3,"previous_page":2,"next_page":null}"#,
+        )
+        .unwrap();
+        assert_eq!(huge_page.get_page(), usize::MAX);
+        assert!(huge_page
+            .map_indexed(|index, _| index)
+            .get_items()
+            .is_empty());
+
+        let mut deserializer =
+            serde_json::Deserializer::from_str(r#"{"items":[],"page":12,"size":10,"total":100}"#);
+        assert_eq!(
+            Page::<u32>::deserialize_recompute(&mut deserializer).unwrap(),
+            page
+        );
+    }
+
+    /// Test the methods of an empty [`Page`] beyond the last page with a huge index.
+    #[test]
+    fn test_page_model_beyond_range_methods() {
+        let records: Vec<u32> = vec![1, 2, 3, 4, 5];
+        let page: Page<u32> = paginate_records_clamped(&records, usize::MAX, 2).unwrap();
+
+        assert!(page.is_valid());
+        assert_eq!(page.remaining_pages(), 0);
+        assert!(!page.last_page_is_full());
+        assert_eq!(page.neighbors(), (Some((2, 2)), None));
+        assert_eq!(
+            page.record_fields(),
+            [
+                ("page", usize::MAX),
+                ("size", 2),
+                ("total", 5),
+                ("pages", 3),
+                ("items", 0)
+            ]
+        );
+
+        // The methods rebuilding the page keep it beyond the last page.
+        let mapped: Page<String> = page
+            .clone()
+            .checked_map(|record| format!("{}", record))
+            .unwrap();
+        assert_eq!(mapped.get_page(), usize::MAX);
+        assert_eq!(mapped.get_previous_page(), Some(2));
+        assert!(mapped.verify_fields().is_ok());
+
+        assert_eq!(page.clone().with_items(vec![]).unwrap(), page);
+        assert!(page
+            .clone()
+            .with_items(vec![1])
+            .unwrap_err()
+            .get_error_kind()
+            .is_out_of_range_page());
+
+        let zipped: Page<(u32, u32)> = page
+            .clone()
+            .zip_with(page.clone(), |record, other| (record, other))
+            .unwrap();
+        assert_eq!(zipped.get_page(), usize::MAX);
+
+        let (items, metadata): (Vec<u32>, PageMetadata) = page.clone().split_off_items();
+        assert_eq!(metadata.attach(items).unwrap(), page);
+
+        let book: Book<u32> = page.into_book();
+        assert_eq!(book.find_record(|record| *record == 1), None);
+    }
+
+    /// Test [`Page`] previous_params and next_params methods.
+    #[test]
+    fn test_page_model_previous_and_next_params() {
+        let records: Vec<u32> = (1..=5).collect();
+
+        let first: Page<u32> = paginate_records(&records, 0, 2).unwrap();
+        assert_eq!(first.previous_params(), None);
+        assert_eq!(first.next_params(), Some((1, 2)));
+
+        let middle: Page<u32> = paginate_records(&records, 1, 2).unwrap();
+        assert_eq!(middle.previous_params(), Some((0, 2)));
+        assert_eq!(middle.next_params(), Some((2, 2)));
+
+        let (page, size): (usize, usize) = middle.next_params().unwrap();
+        let last: Page<u32> = paginate_records(&records, page, size).unwrap();
+        assert_eq!(last.get_items(), &vec![5]);
+        assert_eq!(last.previous_params(), Some((1, 2)));
+        assert_eq!(last.next_params(), None);
+    }
+
+    /// Test [`Page`] is_valid_index and page_count_for_total functions.
+    #[test]
+    fn test_page_model_is_valid_index() {
+        // A size of 0 has a single page.
+        assert_eq!(Page::<u32>::page_count_for_total(10, 0), 1);
+        assert!(Page::<u32>::is_valid_index(0, 10, 0));
+        assert!(!Page::<u32>::is_valid_index(1, 10, 0));
+
+        // An exact multiple of the size.
+        assert_eq!(Page::<u32>::page_count_for_total(9, 3), 3);
+        assert!(Page::<u32>::is_valid_index(2, 9, 3));
+        assert!(!Page::<u32>::is_valid_index(3, 9, 3));
+
+        // A partial last page.
+        assert_eq!(Page::<u32>::page_count_for_total(10, 3), 4);
+        assert!(Page::<u32>::is_valid_index(3, 10, 3));
+        assert!(!Page::<u32>::is_valid_index(4, 10, 3));
+
+        // No records still have the first page.
+        assert!(Page::<u32>::is_valid_index(0, 0, 3));
+        assert!(!Page::<u32>::is_valid_index(1, 0, 3));
+    }
+
+    /// Test [`Page`] reindex method after filtering items in place.
+    #[test]
+    fn test_page_reindex() {
+        let mut page: Page<u32> = Page::new(&vec![5, 6, 7, 8], 1, 4, 10).unwrap();
+
+        page.get_items_mut().retain(|item| item % 2 == 0);
+        assert!(page.verify_fields().is_err());
+
+        let page: Page<u32> = page.reindex();
+        assert_eq!(page.get_items(), &vec![6, 8]);
+        assert_eq!(page.get_page(), 0);
+        assert_eq!(page.get_size(), 2);
+        assert_eq!(page.get_total(), 2);
+        assert_eq!(page.get_pages(), 1);
+        assert_eq!(page.get_previous_page(), None);
+        assert_eq!(page.get_next_page(), None);
+        assert!(page.verify_fields().is_ok());
+    }
+
+    /// Test conversion from [`Page`] into [`Vec`].
+    #[test]
+    fn test_vec_from_page() {
+        let items: Vec<u32> = vec![3, 4];
+        let page: Page<u32> = Page::new(&items, 1, 2, 5).unwrap();
+
+        assert_eq!(Vec::from(page.clone()), items);
+
+        let vec: Vec<u32> = page.into();
+        assert_eq!(vec, items);
+    }
+
+    /// Test [`Page`] as_slice method.
+    #[test]
+    fn test_page_as_slice() {
+        let page: Page<u32> = Page::new(&vec![3, 4], 1, 2, 5).unwrap();
+
+        let slice: &[u32] = page.as_slice();
+        assert_eq!(slice, &[3, 4]);
+        assert_eq!(slice, page.get_items().as_slice());
+
+        assert!(Page::<u32>::default().as_slice().is_empty());
+    }
+
+    /// Test [`Page`] from_parts method.
+    #[test]
+    fn test_page_from_parts() {
+        let items: Vec<u32> = vec![3, 4];
+
+        let page: Page<u32> = Page::from_parts(items.clone(), 1, 2, 5).unwrap();
+        assert_eq!(page, Page::new(&items, 1, 2, 5).unwrap());
+        assert_eq!(page.get_pages(), 3);
+        assert_eq!(page.get_previous_page(), Some(0));
+        assert_eq!(page.get_next_page(), Some(2));
+
+        let error: PaginationError = Page::from_parts(vec![5], 1, 2, 5).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            Page::new(&vec![5], 1, 2, 5).unwrap_err().to_string()
+        );
+    }
+
+    /// Test [`Page`] deserialize_recompute method with wrong and missing derived fields.
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_page_deserialize_recompute() {
+        use serde::Deserialize;
+
+        let wrong_links: &str = r#"{"items": [3, 4], "page": 1, "size": 2, "total": 5, "pages": 9, "previous_page": 4, "next_page": null}"#;
+        assert!(serde_json::from_str::<Page<u32>>(wrong_links).is_err());
+
+        let page: Page<u32> =
+            Page::deserialize_recompute(&mut serde_json::Deserializer::from_str(wrong_links))
+                .unwrap();
+        assert_eq!(page, Page::new(&vec![3, 4], 1, 2, 5).unwrap());
+
+        #[derive(Deserialize)]
+        struct Body {
+            #[serde(deserialize_with = "Page::deserialize_recompute")]
+            page: Page<u32>,
+        }
+
+        let body: Body =
+            serde_json::from_str(r#"{"page": {"items": [5], "page": 2, "size": 2, "total": 5}}"#)
+                .unwrap();
+        assert_eq!(body.page, Page::new(&vec![5], 2, 2, 5).unwrap());
+
+        let wrong_items: &str = r#"{"items": [3], "page": 1, "size": 2, "total": 5}"#;
+        assert!(
+            Page::<u32>::deserialize_recompute(&mut serde_json::Deserializer::from_str(
+                wrong_items
+            ))
+            .is_err()
+        );
+    }
+
+    /// Test [`Page`] from_exact method.
+    #[test]
+    fn test_page_from_exact() {
+        let page: Page<u16> = Page::from_exact(1..=2, 0, 2, 5).unwrap();
+        assert_eq!(page, Page::new(&vec![1, 2], 0, 2, 5).unwrap());
+        assert_eq!(page.get_items().capacity(), 2);
+
+        let mapped_page: Page<String> =
+            Page::from_exact(vec![5].into_iter().map(|item| item.to_string()), 2, 2, 5).unwrap();
+        assert_eq!(mapped_page.get_items(), &vec![String::from("5")]);
+
+        assert!(Page::from_exact(1..4_u32, 0, 2, 5).is_err());
+    }
+
+    /// Test [`Page`] get_total_on_page method.
+    #[test]
+    fn test_page_get_total_on_page() {
+        let last_page: Page<u32> = Page::new(&vec![9], 4, 2, 9).unwrap();
+        assert_eq!(last_page.get_total_on_page(), 1);
+        assert!(last_page.get_total_on_page() < last_page.get_size());
+
+        let full_page: Page<u32> = Page::new(&vec![3, 4], 1, 2, 9).unwrap();
+        assert_eq!(full_page.get_total_on_page(), full_page.get_size());
+
+        assert_eq!(Page::<u32>::empty(10).get_total_on_page(), 0);
+    }
+
+    /// Test [`PaginationResultExt`] map_page and and_then_page methods.
+    #[test]
+    fn test_pagination_result_map_page() {
+        let records: Vec<u32> = (1..=5).collect();
+
+        let page: Page<String> = paginate_records(&records, 1, 2)
+            .map_page(|record| format!("record-{}", record))
+            .unwrap();
+        assert_eq!(
+            page.get_items(),
+            &vec![String::from("record-3"), String::from("record-4")]
+        );
+        assert_eq!(page.get_page(), 1);
+        assert_eq!(page.get_size(), 2);
+        assert_eq!(page.get_total(), 5);
+
+        let error: PaginationError = paginate_records(&records, 3, 2)
+            .map_page(|record| record * 10)
+            .unwrap_err();
+        assert!(error.get_error_kind().is_out_of_range_page());
+
+        let parsed_page: Page<u32> = paginate_records(&vec!["1", "2", "x"], 0, 2)
+            .and_then_page(|record| {
+                record.parse::<u32>().map_err(|error| {
+                    PaginationError::from(ErrorKind::FieldValueError(error.to_string()))
+                })
+            })
+            .unwrap();
+        assert_eq!(parsed_page.get_items(), &vec![1, 2]);
+        assert_eq!(parsed_page.get_total(), 3);
+
+        let parse_error: PaginationError = paginate_records(&vec!["1", "2", "x"], 1, 2)
+            .and_then_page(|record| {
+                record.parse::<u32>().map_err(|error| {
+                    PaginationError::from(ErrorKind::FieldValueError(error.to_string()))
+                })
+            })
+            .unwrap_err();
+        assert!(parse_error.get_error_kind().is_field_value_error());
+    }
 }
@@ -0,0 +1,71 @@
+/// Test page request model.
+#[cfg(test)]
+pub mod test_page_request {
+    use page_hunter::*;
+
+    /// Test [`PageRequest`] builder defaults.
+    #[test]
+    fn test_page_request_builder_defaults() {
+        let request: PageRequest = PageRequest::builder().build();
+        assert_eq!(request.get_page(), 0);
+        assert_eq!(request.get_size(), PageRequest::DEFAULT_SIZE);
+
+        let request: PageRequest = PageRequest::builder()
+            .page(None)
+            .size(None)
+            .default_size(50)
+            .build();
+        assert_eq!(request, PageRequest::new(0, 50));
+
+        let request: PageRequest = PageRequest::builder()
+            .page(Some(3))
+            .size(20)
+            .default_size(50)
+            .build();
+        assert_eq!(request, PageRequest::new(3, 20));
+    }
+
+    /// Test [`PageRequest`] builder clamping to the maximum size.
+    #[test]
+    fn test_page_request_builder_max_size() {
+        let request: PageRequest = PageRequest::builder().size(500).max_size(100).build();
+        assert_eq!(request.get_size(), 100);
+
+        let request: PageRequest = PageRequest::builder()
+            .default_size(200)
+            .max_size(100)
+            .build();
+        assert_eq!(request.get_size(), 100);
+    }
+
+    /// Test [`PageRequest`] validated method.
+    #[test]
+    fn test_page_request_validated() {
+        assert_eq!(
+            PageRequest::new(1, 100).validated(100).unwrap(),
+            PageRequest::new(1, 100)
+        );
+
+        let error: PaginationError = PageRequest::new(1, 101).validated(100).unwrap_err();
+        assert!(error.get_error_kind().is_field_value_error());
+        assert_eq!(
+            error.to_string(),
+            "FIELD VALUE ERROR- Page size '101' exceeds the maximum size '100'"
+        );
+
+        let error: PaginationError = PageRequest::new(1, 0).validated(100).unwrap_err();
+        assert!(error.get_error_kind().is_field_value_error());
+    }
+
+    /// Test [`PageRequest`] conversions from and into a tuple.
+    #[test]
+    fn test_page_request_tuple_conversions() {
+        let request: PageRequest = PageRequest::from((2, 5));
+        assert_eq!(request, PageRequest::new(2, 5));
+
+        let records: Vec<u32> = (1..=12).collect();
+        let (page, size): (usize, usize) = request.into();
+        let page: Page<u32> = paginate_records(&records, page, size).unwrap();
+        assert_eq!(page.get_items(), &vec![11, 12]);
+    }
+}
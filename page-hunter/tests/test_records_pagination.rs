@@ -30,6 +30,32 @@ pub mod test_records_pagination {
 
         let pagination_result: PaginationResult<Page<u8>> = paginate_records(&records, page, size);
         assert!(pagination_result.is_err());
+
+        let error: PaginationError = paginate_records(&records, usize::MAX, size).unwrap_err();
+        assert!(error.get_error_kind().is_out_of_range_page());
+    }
+
+    /// Test [`paginate_records`] function with a large exact-sized collection.
+    #[test]
+    fn test_paginate_records_exact_size() {
+        let records: Vec<u32> = (0..1_000_000).collect();
+
+        let page_model: Page<u32> = paginate_records(&records, 3, 10).unwrap();
+        assert_eq!(page_model.get_items(), &(30..40).collect::<Vec<u32>>());
+        assert_eq!(page_model.get_total(), 1_000_000);
+        assert_eq!(page_model.get_pages(), 100_000);
+    }
+
+    /// Test [`paginate_records`] function with records whose iterator is not exact-sized.
+    #[test]
+    fn test_paginate_records_not_exact_size() {
+        let records = (1..=10_u8).filter(|record| record % 2 == 0);
+        assert_ne!(records.size_hint(), (5, Some(5)));
+
+        let page_model: Page<u8> = paginate_records(&records, 1, 2).unwrap();
+        assert_eq!(page_model.get_items(), &vec![6, 8]);
+        assert_eq!(page_model.get_total(), 5);
+        assert_eq!(page_model.get_pages(), 3);
     }
 
     /// Test failed result of [`paginate_records`] function.
@@ -94,4 +120,249 @@ pub mod test_records_pagination {
         let book: Book<u8> = pagination_result.unwrap();
         assert_eq!(book.get_sheets().len(), 0);
     }
+
+    /// Test [`validate_request`] function with an in-range page.
+    #[test]
+    fn test_validate_request_in_range() {
+        assert!(validate_request(0, 3, 10).is_ok());
+        assert!(validate_request(3, 3, 10).is_ok());
+        assert!(validate_request(0, 3, 0).is_ok());
+        assert!(validate_request(0, 0, 10).is_ok());
+    }
+
+    /// Test [`validate_request`] function with an out-of-range page.
+    #[test]
+    fn test_validate_request_out_of_range() {
+        let validation_result: PaginationResult<()> = validate_request(4, 3, 10);
+        assert!(validation_result.is_err());
+
+        let records: Vec<u8> = vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+        let pagination_result: PaginationResult<Page<u8>> = paginate_records(&records, 4, 3);
+
+        assert_eq!(
+            validation_result.unwrap_err().to_string(),
+            pagination_result.unwrap_err().to_string()
+        );
+        assert_eq!(
+            validate_request(1, 3, 0).unwrap_err().to_string(),
+            "OUT OF RANGE PAGE ERROR- Page index '1' exceeds total pages '1'"
+        );
+    }
+
+    /// Test [`total_from_count`] function with a valid count.
+    #[test]
+    fn test_total_from_count() {
+        assert_eq!(total_from_count(100_i64).unwrap(), 100);
+        assert_eq!(total_from_count(0_i64).unwrap(), 0);
+        assert_eq!(total_from_count(7_u32).unwrap(), 7);
+    }
+
+    /// Test [`total_from_count`] function with an out of range count.
+    #[test]
+    fn test_total_from_count_out_of_range() {
+        let total_result: PaginationResult<usize> = total_from_count(-1_i64);
+        assert!(total_result.is_err());
+        assert_eq!(
+            total_result.unwrap_err().to_string(),
+            "FIELD VALUE ERROR- Total records count '-1' is out of range"
+        );
+
+        assert!(total_from_count(i128::MAX).is_err());
+
+        #[cfg(target_pointer_width = "32")]
+        assert!(total_from_count(u32::MAX as i64 + 1).is_err());
+    }
+
+    /// Test [`merge_books`] function concatenating two books.
+    #[test]
+    fn test_merge_books() {
+        let first_shard: Book<u8> = bind_records(&vec![1, 2, 3], 2).unwrap();
+        let second_shard: Book<u8> = bind_records(&vec![4, 5, 6, 7], 3).unwrap();
+
+        let book: Book<u8> = merge_books(&[first_shard, second_shard], 3).unwrap();
+        assert_eq!(book.get_sheets().len(), 3);
+        assert_eq!(
+            book.clone()
+                .into_iter()
+                .flat_map(|sheet| sheet.into_iter())
+                .collect::<Vec<u8>>(),
+            vec![1, 2, 3, 4, 5, 6, 7]
+        );
+        for sheet in book.get_sheets() {
+            assert_eq!(sheet.get_total(), 7);
+            assert_eq!(sheet.get_pages(), 3);
+            assert_eq!(sheet.get_size(), 3);
+        }
+        assert_eq!(book.get_sheets()[2].get_items(), &vec![7]);
+        assert!(book.verify().is_ok());
+
+        let empty_book: Book<u8> = merge_books(&[], 3).unwrap();
+        assert_eq!(empty_book.get_sheets().len(), 1);
+        assert_eq!(empty_book.get_sheets()[0].get_total(), 0);
+    }
+
+    /// Test paginate_slice function matches paginate_records.
+    #[test]
+    fn test_paginate_slice() {
+        let records: Vec<String> = (1..=11).map(|number| number.to_string()).collect();
+
+        for page in 0..5 {
+            assert_eq!(
+                paginate_slice(&records, page, 3).map_err(|error| error.to_string()),
+                paginate_records(&records, page, 3).map_err(|error| error.to_string())
+            );
+        }
+
+        let page: Page<String> = paginate_slice(&records[..4], 1, 3).unwrap();
+        assert_eq!(page.get_items(), &vec![String::from("4")]);
+        assert_eq!(page.get_total(), 4);
+
+        assert!(paginate_slice(&records, usize::MAX, 3).is_err());
+        assert_eq!(paginate_slice::<u32>(&[], 0, 3).unwrap().get_pages(), 1);
+    }
+
+    /// Test bind_records_iter function yields the pages of bind_records.
+    #[test]
+    fn test_bind_records_iter() {
+        let records: Vec<u32> = (1..=11).collect();
+
+        let book: Book<u32> = bind_records(&records, 3).unwrap();
+        let pages: Vec<Page<u32>> = bind_records_iter(&records, 3)
+            .collect::<PaginationResult<Vec<Page<u32>>>>()
+            .unwrap();
+        assert_eq!(pages.len(), 4);
+        assert_eq!(&pages, book.get_sheets());
+
+        let mut pages = bind_records_iter(&records, 5);
+        assert_eq!(
+            pages.next().unwrap().unwrap().get_items(),
+            &vec![1, 2, 3, 4, 5]
+        );
+        assert_eq!(pages.next().unwrap().unwrap().get_page(), 1);
+
+        let empty: Vec<u32> = Vec::new();
+        let pages: Vec<PaginationResult<Page<u32>>> = bind_records_iter(&empty, 3).collect();
+        assert_eq!(pages.len(), 1);
+        assert_eq!(bind_records_iter(&records, 0).count(), 0);
+    }
+
+    /// Test paginate_into function matches paginate_records.
+    #[test]
+    fn test_paginate_into() {
+        let records: Vec<String> = (1..=11).map(|number| number.to_string()).collect();
+
+        for page in 0..4 {
+            assert_eq!(
+                paginate_into(records.clone(), page, 3).unwrap(),
+                paginate_records(&records, page, 3).unwrap()
+            );
+        }
+
+        let last: Page<String> = paginate_into(records.clone(), 3, 3).unwrap();
+        assert_eq!(last.get_items(), &vec!["10".to_string(), "11".to_string()]);
+        assert_eq!(last.get_total(), 11);
+
+        // Records without Clone can be paginated from an iterator.
+        struct Record(u32);
+        let page: Page<Record> = paginate_into((1..=5).map(Record), 1, 2).unwrap();
+        assert_eq!(page.get_items()[0].0, 3);
+        assert_eq!(page.get_total(), 5);
+
+        let error: PaginationError = paginate_into(records, 4, 3).unwrap_err();
+        assert!(error.get_error_kind().is_out_of_range_page());
+
+        let error: PaginationError = paginate_into(vec![1, 2, 3], usize::MAX, 2).unwrap_err();
+        assert!(error.get_error_kind().is_out_of_range_page());
+    }
+
+    /// Test [`par_bind_records`] function against [`bind_records`].
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_par_bind_records() {
+        let records: Vec<u32> = (0..10_000).collect();
+
+        for size in [0, 7, 100, 10_000, 20_000] {
+            let book: Book<u32> = par_bind_records(&records, size).unwrap();
+            let expected_book: Book<u32> = bind_records(&records, size).unwrap();
+
+            assert_eq!(book.get_sheets().len(), expected_book.get_sheets().len());
+            for (sheet, expected_sheet) in book.get_sheets().iter().zip(expected_book.get_sheets())
+            {
+                assert_eq!(sheet, expected_sheet);
+            }
+        }
+
+        let empty_book: Book<u32> = par_bind_records(&[], 10).unwrap();
+        assert_eq!(
+            empty_book.get_sheets(),
+            bind_records(&Vec::<u32>::new(), 10).unwrap().get_sheets()
+        );
+    }
+
+    /// Test [`paginate_records_rev`] function.
+    #[test]
+    fn test_paginate_records_rev() {
+        let records: Vec<u32> = (1..=10).collect();
+
+        let first_page: Page<u32> = paginate_records_rev(&records, 0, 3).unwrap();
+        assert_eq!(first_page.get_items(), &vec![8, 9, 10]);
+        assert_eq!(first_page.get_total(), 10);
+        assert_eq!(first_page.get_pages(), 4);
+        assert_eq!(first_page.get_previous_page(), None);
+        assert_eq!(first_page.get_next_page(), Some(1));
+
+        assert_eq!(
+            paginate_records_rev(&records, 1, 3).unwrap().get_items(),
+            &vec![5, 6, 7]
+        );
+        assert_eq!(
+            paginate_records_rev(&records, 2, 3).unwrap().get_items(),
+            &vec![2, 3, 4]
+        );
+
+        let last_page: Page<u32> = paginate_records_rev(&records, 3, 3).unwrap();
+        assert_eq!(last_page.get_items(), &vec![1]);
+        assert_eq!(last_page.get_previous_page(), Some(2));
+        assert_eq!(last_page.get_next_page(), None);
+
+        assert!(paginate_records_rev(&records, 4, 3)
+            .unwrap_err()
+            .get_error_kind()
+            .is_out_of_range_page());
+        assert_eq!(
+            paginate_records_rev(&Vec::<u32>::new(), 0, 3)
+                .unwrap()
+                .get_items(),
+            &Vec::<u32>::new()
+        );
+    }
+
+    /// Test paginate_records_clamped function within and beyond the range of the records.
+    #[test]
+    fn test_paginate_records_clamped() {
+        let records: Vec<u32> = vec![1, 2, 3, 4, 5];
+
+        let page: Page<u32> = paginate_records_clamped(&records, 10, 2).unwrap();
+        assert!(page.get_items().is_empty());
+        assert_eq!(page.get_page(), 10);
+        assert_eq!(page.get_size(), 2);
+        assert_eq!(page.get_total(), 5);
+        assert_eq!(page.get_pages(), 3);
+        assert_eq!(page.get_previous_page(), Some(2));
+        assert_eq!(page.get_next_page(), None);
+        assert!(page.verify_fields().is_ok());
+
+        assert!(paginate_records(&records, 10, 2).is_err());
+        assert_eq!(
+            paginate_records_clamped(&records, 1, 2).unwrap(),
+            paginate_records(&records, 1, 2).unwrap()
+        );
+
+        let empty_records: Vec<u32> = Vec::new();
+        let empty_page: Page<u32> = paginate_records_clamped(&empty_records, 1, 2).unwrap();
+        assert!(empty_page.get_items().is_empty());
+        assert_eq!(empty_page.get_total(), 0);
+        assert_eq!(empty_page.get_previous_page(), Some(0));
+        assert!(empty_page.verify_fields().is_ok());
+    }
 }
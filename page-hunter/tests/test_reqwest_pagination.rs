@@ -0,0 +1,154 @@
+// Test reqwest pagination functions.
+#[cfg(feature = "reqwest")]
+#[cfg(test)]
+mod test_reqwest_pagination {
+    use page_hunter::*;
+    use serde_json::json;
+    use wiremock::matchers::{method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    /// Start a mock server serving two pages of records at `/records`.
+    async fn mock_server() -> MockServer {
+        let server: MockServer = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/records"))
+            .and(query_param("page", "0"))
+            .and(query_param("size", "2"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "items": [1, 2],
+                "page": 0,
+                "size": 2,
+                "total": 3,
+                "pages": 2,
+                "previous_page": null,
+                "next_page": 1,
+            })))
+            .mount(&server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/records"))
+            .and(query_param("page", "1"))
+            .and(query_param("size", "2"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "items": [3],
+                "page": 1,
+                "size": 2,
+                "total": 3,
+                "pages": 2,
+                "previous_page": 0,
+                "next_page": null,
+            })))
+            .mount(&server)
+            .await;
+
+        server
+    }
+
+    /// Test [`fetch_page`] function.
+    #[tokio::test]
+    async fn test_fetch_page() {
+        let server: MockServer = mock_server().await;
+        let client: reqwest::Client = reqwest::Client::new();
+        let url: String = format!("{}/records", server.uri());
+
+        let page: Page<u32> = fetch_page(&client, &url, 1, 2).await.unwrap();
+        assert_eq!(page, Page::new(&vec![3], 1, 2, 3).unwrap());
+    }
+
+    /// Test [`walk_pages`] function following the next page of each fetched page.
+    #[tokio::test]
+    async fn test_walk_pages() {
+        let server: MockServer = mock_server().await;
+        let client: reqwest::Client = reqwest::Client::new();
+        let url: String = format!("{}/records", server.uri());
+
+        let mut walker: PageWalker<u32> = walk_pages(&client, &url, 2);
+
+        let mut items: Vec<u32> = Vec::new();
+        let mut pages: usize = 0;
+        while let Some(page) = walker.next().await {
+            let page: Page<u32> = page.unwrap();
+            items.extend(page.get_items().iter().copied());
+            pages += 1;
+        }
+
+        assert_eq!(pages, 2);
+        assert_eq!(items, vec![1, 2, 3]);
+        assert!(walker.next().await.is_none());
+    }
+
+    /// Test [`fetch_page`] function with a non-success status code.
+    #[tokio::test]
+    async fn test_fetch_page_error_status() {
+        let server: MockServer = mock_server().await;
+        let client: reqwest::Client = reqwest::Client::new();
+        let url: String = format!("{}/records", server.uri());
+
+        let error: PaginationError = fetch_page::<u32>(&client, &url, 2, 2).await.unwrap_err();
+        assert!(error.get_error_kind().is_reqwest_error());
+        assert!(error.to_string().starts_with("REQWEST ERROR- "));
+        assert!(error.to_string().contains("404"));
+    }
+
+    /// Test [`fetch_page`] function with a response body that is not a valid page.
+    #[tokio::test]
+    async fn test_fetch_page_invalid_body() {
+        let server: MockServer = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/records"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "items": [1, 2, 3],
+                "page": 0,
+                "size": 2,
+                "total": 3,
+                "pages": 2,
+                "previous_page": null,
+                "next_page": 1,
+            })))
+            .mount(&server)
+            .await;
+
+        let client: reqwest::Client = reqwest::Client::new();
+        let url: String = format!("{}/records", server.uri());
+
+        let mut walker: PageWalker<u32> = walk_pages(&client, &url, 2);
+        let error: PaginationError = walker.next().await.unwrap().unwrap_err();
+        assert!(error.get_error_kind().is_reqwest_error());
+        assert!(walker.next().await.is_none());
+    }
+
+    /// Test [`walk_pages`] function stops when the server ignores the requested page.
+    #[tokio::test]
+    async fn test_walk_pages_page_mismatch() {
+        let server: MockServer = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/records"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "items": [1, 2],
+                "page": 0,
+                "size": 2,
+                "total": 3,
+                "pages": 2,
+                "previous_page": null,
+                "next_page": 1,
+            })))
+            .mount(&server)
+            .await;
+
+        let client: reqwest::Client = reqwest::Client::new();
+        let url: String = format!("{}/records", server.uri());
+
+        let mut walker: PageWalker<u32> = walk_pages(&client, &url, 2);
+        assert!(walker.next().await.unwrap().is_ok());
+
+        let error: PaginationError = walker.next().await.unwrap().unwrap_err();
+        assert!(error.get_error_kind().is_field_value_error());
+        assert_eq!(
+            error.to_string(),
+            "FIELD VALUE ERROR- Fetched page index error: expected '1', found '0'"
+        );
+        assert!(walker.next().await.is_none());
+    }
+}
@@ -0,0 +1,68 @@
+/// Test schemars features implementation.
+#[cfg(feature = "schemars")]
+#[cfg(test)]
+pub mod test_schemars_features {
+    use page_hunter::{Book, Page};
+    use schemars::{schema::RootSchema, schema_for, JsonSchema};
+    use serde_json::{json, to_value, Value};
+
+    #[derive(JsonSchema)]
+    #[allow(dead_code)]
+    struct Record {
+        number: u8,
+    }
+
+    /// Test [`Page`] JSON schema generation.
+    #[test]
+    fn test_page_json_schema() {
+        let schema: RootSchema = schema_for!(Page<Record>);
+        let schema: Value = to_value(&schema).unwrap();
+
+        assert_eq!(schema["title"], json!("Page_of_Record"));
+        assert_eq!(schema["type"], json!("object"));
+        assert_eq!(
+            schema["required"],
+            json!(["items", "page", "pages", "size", "total"])
+        );
+
+        let properties: &Value = &schema["properties"];
+        assert_eq!(properties["items"]["type"], json!("array"));
+        assert_eq!(
+            properties["items"]["items"]["$ref"],
+            json!("#/definitions/Record")
+        );
+        for field in ["page", "size", "total"] {
+            assert_eq!(properties[field]["type"], json!("integer"));
+            assert_eq!(properties[field]["minimum"], json!(0.0));
+        }
+        assert_eq!(properties["pages"]["type"], json!("integer"));
+        assert_eq!(properties["pages"]["minimum"], json!(1.0));
+        #[cfg(not(feature = "serde-camel-case"))]
+        let link_fields: [&str; 2] = ["previous_page", "next_page"];
+        #[cfg(feature = "serde-camel-case")]
+        let link_fields: [&str; 2] = ["previousPage", "nextPage"];
+        for field in link_fields {
+            assert_eq!(properties[field]["type"], json!(["integer", "null"]));
+            assert_eq!(properties[field]["minimum"], json!(0.0));
+        }
+    }
+
+    /// Test [`Book`] JSON schema generation.
+    #[test]
+    fn test_book_json_schema() {
+        let schema: RootSchema = schema_for!(Book<Record>);
+        let schema: Value = to_value(&schema).unwrap();
+
+        assert_eq!(schema["title"], json!("Book_of_Record"));
+        assert_eq!(schema["required"], json!(["sheets"]));
+        assert_eq!(schema["properties"]["sheets"]["type"], json!("array"));
+        assert_eq!(
+            schema["properties"]["sheets"]["items"]["$ref"],
+            json!("#/definitions/Page_of_Record")
+        );
+        assert_eq!(
+            schema["definitions"]["Page_of_Record"]["properties"]["page"]["type"],
+            json!("integer")
+        );
+    }
+}
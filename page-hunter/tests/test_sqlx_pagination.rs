@@ -68,9 +68,9 @@ pub mod test_postgres_pagination {
         assert_eq!(users.get_items()[1].hashed_password, "hashed_password8");
         assert_eq!(users.get_items()[2].hashed_password, "hashed_password9");
 
-        assert_eq!(users.get_items()[0].is_active, true);
-        assert_eq!(users.get_items()[1].is_active, true);
-        assert_eq!(users.get_items()[2].is_active, true);
+        assert!(users.get_items()[0].is_active);
+        assert!(users.get_items()[1].is_active);
+        assert!(users.get_items()[2].is_active);
 
         assert!(users.get_items()[0].updated_at.is_none());
         assert!(users.get_items()[1].updated_at.is_none());
@@ -125,7 +125,7 @@ pub mod test_postgres_pagination {
         let error: String = users_pagination.unwrap_err().to_string();
         assert_eq!(
             error,
-            "SQLX ERROR- error returned from database: syntax error at or near \";\"".to_string(),
+            "SQLX ERROR- error returned from database: syntax error at or near \";\" (COUNT query failed for page '2' and size '3')".to_string(),
         )
     }
 
@@ -175,75 +175,1643 @@ pub mod test_postgres_pagination {
         let users_pagination: PaginationResult<Page<User>> = query.paginate(&pool, 2, 3).await;
         assert!(users_pagination.is_err());
 
+        let error: PaginationError = users_pagination.unwrap_err();
+        assert!(error.get_error_kind().is_from_row_error());
+        assert!(!error.get_error_kind().is_sqlx_error());
+        assert_eq!(
+            error.to_string(),
+            "FROM ROW ERROR- no column found for name: age".to_string(),
+        )
+    }
+
+    /// Test pagination with invalid page
+    #[tokio::test]
+    async fn test_pagination_invalid_page() {
+        use sqlx::postgres::PgPoolOptions;
+        use sqlx::{FromRow, PgPool, Postgres, QueryBuilder};
+        use time::OffsetDateTime;
+        use uuid::Uuid;
+
+        let db_host: String = env::var("DB_HOST").expect("DB_HOST var not found");
+        let db_port: String = env::var("PG_DB_PORT").expect("PG_DB_PORT var not found");
+        let db_user: String = env::var("DB_USER").expect("DB_USER var not found");
+        let db_password: String = env::var("DB_PASSWORD").expect("DB_PASSWORD var not found");
+        let db_name: String = env::var("DB_NAME").expect("DB_NAME var not found");
+
+        #[derive(Clone, Debug, FromRow)]
+        #[allow(dead_code)]
+        pub struct User {
+            id: Uuid,
+            username: String,
+            hashed_password: String,
+            is_active: bool,
+            created_at: OffsetDateTime,
+            updated_at: Option<OffsetDateTime>,
+        }
+
+        let pool: PgPool = match PgPoolOptions::new()
+            .max_connections(1)
+            .connect(&format!(
+                "postgres://{}:{}@{}:{}/{}",
+                db_user, db_password, db_host, db_port, db_name
+            ))
+            .await
+        {
+            Ok(pool) => pool,
+            Err(e) => {
+                panic!("Failed to connect to Postgres: {:?}", e);
+            }
+        };
+
+        let query: QueryBuilder<Postgres> =
+            QueryBuilder::<Postgres>::new("SELECT * FROM test_page_hunter.users");
+
+        let users_pagination: PaginationResult<Page<User>> = query.paginate(&pool, 5, 30).await;
+        assert!(users_pagination.is_err());
+
+        let error: String = users_pagination.unwrap_err().to_string();
+        assert_eq!(
+            error,
+            "OUT OF RANGE PAGE ERROR- Page index '5' exceeds total pages '4'".to_string(),
+        )
+    }
+
+    /// Test count matches the total of a paginated result
+    #[tokio::test]
+    async fn test_count_matches_pagination_total() {
+        use sqlx::postgres::PgPoolOptions;
+        use sqlx::{FromRow, PgPool, Postgres, QueryBuilder};
+        use time::OffsetDateTime;
+        use uuid::Uuid;
+
+        let db_host: String = env::var("DB_HOST").expect("DB_HOST var not found");
+        let db_port: String = env::var("PG_DB_PORT").expect("PG_DB_PORT var not found");
+        let db_user: String = env::var("DB_USER").expect("DB_USER var not found");
+        let db_password: String = env::var("DB_PASSWORD").expect("DB_PASSWORD var not found");
+        let db_name: String = env::var("DB_NAME").expect("DB_NAME var not found");
+
+        #[derive(Clone, FromRow)]
+        #[allow(dead_code)]
+        pub struct User {
+            id: Uuid,
+            username: String,
+            hashed_password: String,
+            is_active: bool,
+            created_at: OffsetDateTime,
+            updated_at: Option<OffsetDateTime>,
+        }
+
+        let pool: PgPool = match PgPoolOptions::new()
+            .max_connections(1)
+            .connect(&format!(
+                "postgres://{}:{}@{}:{}/{}",
+                db_user, db_password, db_host, db_port, db_name
+            ))
+            .await
+        {
+            Ok(pool) => pool,
+            Err(e) => {
+                panic!("Failed to connect to Postgres: {:?}", e);
+            }
+        };
+
+        let query: QueryBuilder<Postgres> =
+            QueryBuilder::<Postgres>::new("SELECT * FROM test_page_hunter.users");
+
+        let users_count: PaginationResult<usize> = query.count(&pool).await;
+        assert!(users_count.is_ok());
+
+        let users_pagination: PaginationResult<Page<User>> = query.paginate(&pool, 2, 3).await;
+        assert!(users_pagination.is_ok());
+
+        assert_eq!(users_count.unwrap(), 100);
+        assert_eq!(users_pagination.unwrap().get_total(), 100);
+    }
+
+    /// Test pagination inside a transaction
+    #[tokio::test]
+    async fn test_pagination_in_transaction() {
+        use sqlx::postgres::PgPoolOptions;
+        use sqlx::{FromRow, PgPool, Postgres, QueryBuilder, Transaction};
+        use time::OffsetDateTime;
+        use uuid::Uuid;
+
+        let db_host: String = env::var("DB_HOST").expect("DB_HOST var not found");
+        let db_port: String = env::var("PG_DB_PORT").expect("PG_DB_PORT var not found");
+        let db_user: String = env::var("DB_USER").expect("DB_USER var not found");
+        let db_password: String = env::var("DB_PASSWORD").expect("DB_PASSWORD var not found");
+        let db_name: String = env::var("DB_NAME").expect("DB_NAME var not found");
+
+        #[derive(Clone, FromRow)]
+        #[allow(dead_code)]
+        pub struct User {
+            id: Uuid,
+            username: String,
+            hashed_password: String,
+            is_active: bool,
+            created_at: OffsetDateTime,
+            updated_at: Option<OffsetDateTime>,
+        }
+
+        let pool: PgPool = match PgPoolOptions::new()
+            .max_connections(1)
+            .connect(&format!(
+                "postgres://{}:{}@{}:{}/{}",
+                db_user, db_password, db_host, db_port, db_name
+            ))
+            .await
+        {
+            Ok(pool) => pool,
+            Err(e) => {
+                panic!("Failed to connect to Postgres: {:?}", e);
+            }
+        };
+
+        let mut transaction: Transaction<Postgres> = pool.begin().await.unwrap();
+
+        let query: QueryBuilder<Postgres> =
+            QueryBuilder::<Postgres>::new("SELECT * FROM test_page_hunter.users");
+
+        let users_pagination: PaginationResult<Page<User>> =
+            query.paginate(&mut transaction, 33, 3).await;
+        assert!(users_pagination.is_ok());
+
+        let users_count: PaginationResult<usize> = query.count(&mut *transaction).await;
+        assert!(users_count.is_ok());
+
+        transaction.rollback().await.unwrap();
+
+        let users: Page<User> = users_pagination.unwrap();
+        assert_eq!(users.get_total(), users_count.unwrap());
+        assert_eq!(users.get_items().len(), 1);
+        assert_eq!(users.get_pages(), 34);
+    }
+
+    /// Test generated SQL omits OFFSET for the first page
+    #[test]
+    fn test_paginated_sql() {
+        use sqlx::{Postgres, QueryBuilder};
+
+        let query: QueryBuilder<Postgres> =
+            QueryBuilder::<Postgres>::new("SELECT * FROM test_page_hunter.users");
+
+        assert_eq!(
+            query.paginated_sql(0, 3).unwrap(),
+            "WITH temp_table AS (SELECT * FROM test_page_hunter.users) SELECT * from temp_table LIMIT 3;"
+        );
+        assert_eq!(
+            query.paginated_sql(2, 3).unwrap(),
+            "WITH temp_table AS (SELECT * FROM test_page_hunter.users) SELECT * from temp_table LIMIT 3 OFFSET 6;"
+        );
+        assert!(query
+            .paginated_sql(usize::MAX, 3)
+            .unwrap_err()
+            .get_error_kind()
+            .is_field_value_error());
+    }
+
+    /// Test database error when is not possible to fetch the records by invalid query
+    #[tokio::test]
+    async fn test_error_fetching_records() {
+        use sqlx::postgres::PgPoolOptions;
+        use sqlx::{FromRow, PgPool, Postgres, QueryBuilder};
+        use uuid::Uuid;
+
+        let db_host: String = env::var("DB_HOST").expect("DB_HOST var not found");
+        let db_port: String = env::var("PG_DB_PORT").expect("PG_DB_PORT var not found");
+        let db_user: String = env::var("DB_USER").expect("DB_USER var not found");
+        let db_password: String = env::var("DB_PASSWORD").expect("DB_PASSWORD var not found");
+        let db_name: String = env::var("DB_NAME").expect("DB_NAME var not found");
+
+        #[derive(Clone, Debug, FromRow)]
+        #[allow(dead_code)]
+        pub struct User {
+            id: Uuid,
+            username_number: i32,
+        }
+
+        let pool: PgPool = match PgPoolOptions::new()
+            .max_connections(1)
+            .connect(&format!(
+                "postgres://{}:{}@{}:{}/{}",
+                db_user, db_password, db_host, db_port, db_name
+            ))
+            .await
+        {
+            Ok(pool) => pool,
+            Err(e) => {
+                panic!("Failed to connect to Postgres: {:?}", e);
+            }
+        };
+
+        // The cast is not evaluated by the count query, because the column is not used to count the records.
+        let query: QueryBuilder<Postgres> = QueryBuilder::<Postgres>::new(
+            "SELECT id, CAST(username AS integer) AS username_number FROM test_page_hunter.users",
+        );
+
+        let users_pagination: PaginationResult<Page<User>> = query.paginate(&pool, 2, 3).await;
+        assert!(users_pagination.is_err());
+
+        let error: PaginationError = users_pagination.unwrap_err();
+        assert!(error.get_error_kind().is_sqlx_error());
+        assert_eq!(
+            error.get_context(),
+            Some("FETCH query failed for page '2' and size '3'")
+        );
+    }
+
+    /// Test ORDER BY clause with nulls order and collation
+    #[test]
+    fn test_order_by_display() {
+        let order_by: OrderBy = OrderBy::new(&vec![
+            SortColumn::new(
+                "updated_at",
+                SortDirection::Desc,
+                Some(NullsOrder::Last),
+                None,
+            ),
+            SortColumn::new(
+                "username",
+                SortDirection::Asc,
+                Some(NullsOrder::First),
+                Some("en_US"),
+            ),
+            SortColumn::new("id", SortDirection::Asc, None, None),
+        ]);
+
+        assert_eq!(
+            order_by.to_string(),
+            "ORDER BY updated_at DESC NULLS LAST, username COLLATE \"en_US\" ASC NULLS FIRST, id ASC"
+        );
+        assert_eq!(
+            order_by.expression(),
+            "updated_at DESC NULLS LAST, username COLLATE \"en_US\" ASC NULLS FIRST, id ASC"
+        );
+        assert_eq!(OrderBy::default().to_string(), "");
+        assert_eq!(OrderBy::default().expression(), "");
+    }
+
+    /// Test pagination ordered by a nullable column does not skip records
+    #[tokio::test]
+    async fn test_pagination_ordered_by_nullable_column() {
+        use sqlx::postgres::PgPoolOptions;
+        use sqlx::{FromRow, PgPool, Postgres, QueryBuilder};
+        use time::OffsetDateTime;
+        use uuid::Uuid;
+
+        let db_host: String = env::var("DB_HOST").expect("DB_HOST var not found");
+        let db_port: String = env::var("PG_DB_PORT").expect("PG_DB_PORT var not found");
+        let db_user: String = env::var("DB_USER").expect("DB_USER var not found");
+        let db_password: String = env::var("DB_PASSWORD").expect("DB_PASSWORD var not found");
+        let db_name: String = env::var("DB_NAME").expect("DB_NAME var not found");
+
+        #[derive(Clone, FromRow)]
+        #[allow(dead_code)]
+        pub struct User {
+            id: Uuid,
+            username: String,
+            hashed_password: String,
+            is_active: bool,
+            created_at: OffsetDateTime,
+            updated_at: Option<OffsetDateTime>,
+        }
+
+        let pool: PgPool = match PgPoolOptions::new()
+            .max_connections(1)
+            .connect(&format!(
+                "postgres://{}:{}@{}:{}/{}",
+                db_user, db_password, db_host, db_port, db_name
+            ))
+            .await
+        {
+            Ok(pool) => pool,
+            Err(e) => {
+                panic!("Failed to connect to Postgres: {:?}", e);
+            }
+        };
+
+        let order_by: OrderBy = OrderBy::new(&vec![
+            SortColumn::new(
+                "updated_at",
+                SortDirection::Asc,
+                Some(NullsOrder::Last),
+                None,
+            ),
+            SortColumn::new("id", SortDirection::Asc, None, None),
+        ]);
+
+        let query: QueryBuilder<Postgres> =
+            QueryBuilder::<Postgres>::new("SELECT * FROM test_page_hunter.users");
+
+        let mut ids: Vec<Uuid> = Vec::new();
+        let mut updated_at_values: Vec<Option<OffsetDateTime>> = Vec::new();
+        for page in 0..10 {
+            let users: Page<User> = query
+                .paginate_ordered(&pool, page, 10, &order_by.expression())
+                .await
+                .unwrap();
+            for user in users {
+                ids.push(user.id);
+                updated_at_values.push(user.updated_at);
+            }
+        }
+
+        assert_eq!(ids.len(), 100);
+        ids.sort();
+        ids.dedup();
+        assert_eq!(ids.len(), 100);
+
+        let first_null: usize = updated_at_values
+            .iter()
+            .position(|updated_at| updated_at.is_none())
+            .unwrap_or(updated_at_values.len());
+        assert!(updated_at_values[first_null..]
+            .iter()
+            .all(|updated_at| updated_at.is_none()));
+    }
+
+    /// Test pagination rejects a zero page size before querying the database
+    #[tokio::test]
+    async fn test_pagination_zero_size() {
+        use sqlx::postgres::PgPoolOptions;
+        use sqlx::{FromRow, PgPool, Postgres, QueryBuilder};
+        use uuid::Uuid;
+
+        let db_host: String = env::var("DB_HOST").expect("DB_HOST var not found");
+        let db_port: String = env::var("PG_DB_PORT").expect("PG_DB_PORT var not found");
+        let db_user: String = env::var("DB_USER").expect("DB_USER var not found");
+        let db_password: String = env::var("DB_PASSWORD").expect("DB_PASSWORD var not found");
+        let db_name: String = env::var("DB_NAME").expect("DB_NAME var not found");
+
+        #[derive(Clone, Debug, FromRow)]
+        #[allow(dead_code)]
+        pub struct User {
+            id: Uuid,
+            username: String,
+        }
+
+        let pool: PgPool = match PgPoolOptions::new()
+            .max_connections(1)
+            .connect(&format!(
+                "postgres://{}:{}@{}:{}/{}",
+                db_user, db_password, db_host, db_port, db_name
+            ))
+            .await
+        {
+            Ok(pool) => pool,
+            Err(e) => {
+                panic!("Failed to connect to Postgres: {:?}", e);
+            }
+        };
+
+        let query: QueryBuilder<Postgres> =
+            QueryBuilder::<Postgres>::new("SELECT id, username FROM test_page_hunter.users");
+
+        let users_pagination: PaginationResult<Page<User>> = query.paginate(&pool, 0, 0).await;
+        assert!(users_pagination.is_err());
+
+        let error: PaginationError = users_pagination.unwrap_err();
+        assert!(error.get_error_kind().is_field_value_error());
+        assert_eq!(
+            error.to_string(),
+            "FIELD VALUE ERROR- Page size must be greater than zero"
+        );
+    }
+
+    /// Test bounded pagination stops early when the budget is exhausted
+    #[tokio::test]
+    async fn test_pagination_bounded() {
+        use sqlx::postgres::PgPoolOptions;
+        use sqlx::{FromRow, PgPool, Postgres, QueryBuilder};
+        use std::time::Duration;
+        use uuid::Uuid;
+
+        let db_host: String = env::var("DB_HOST").expect("DB_HOST var not found");
+        let db_port: String = env::var("PG_DB_PORT").expect("PG_DB_PORT var not found");
+        let db_user: String = env::var("DB_USER").expect("DB_USER var not found");
+        let db_password: String = env::var("DB_PASSWORD").expect("DB_PASSWORD var not found");
+        let db_name: String = env::var("DB_NAME").expect("DB_NAME var not found");
+
+        #[derive(Clone, Debug, FromRow)]
+        #[allow(dead_code)]
+        pub struct User {
+            id: Uuid,
+            username: String,
+        }
+
+        let pool: PgPool = match PgPoolOptions::new()
+            .max_connections(1)
+            .connect(&format!(
+                "postgres://{}:{}@{}:{}/{}",
+                db_user, db_password, db_host, db_port, db_name
+            ))
+            .await
+        {
+            Ok(pool) => pool,
+            Err(e) => {
+                panic!("Failed to connect to Postgres: {:?}", e);
+            }
+        };
+
+        let query: QueryBuilder<Postgres> =
+            QueryBuilder::<Postgres>::new("SELECT id, username FROM test_page_hunter.users");
+
+        let (book, stopped_early): (Book<User>, bool) = query
+            .paginate_bounded(&pool, 10, Duration::ZERO)
+            .await
+            .unwrap();
+        assert!(stopped_early);
+        assert_eq!(book.get_sheets().len(), 1);
+        assert!(book.get_sheets()[0].verify_fields().is_ok());
+        assert_eq!(book.get_sheets()[0].get_total(), 100);
+        assert_eq!(book.get_sheets()[0].get_items()[0].username, "user1");
+
+        let (book, stopped_early): (Book<User>, bool) = query
+            .paginate_bounded(&pool, 10, Duration::from_secs(60))
+            .await
+            .unwrap();
+        assert!(!stopped_early);
+        assert_eq!(book.get_sheets().len(), 10);
+        assert!(book.verify().is_ok());
+    }
+
+    /// Test paginator memoizes the total and matches a direct pagination
+    #[tokio::test]
+    async fn test_paginator_memoized_count() {
+        use sqlx::postgres::PgPoolOptions;
+        use sqlx::{FromRow, PgPool, Postgres, QueryBuilder};
+        use uuid::Uuid;
+
+        let db_host: String = env::var("DB_HOST").expect("DB_HOST var not found");
+        let db_port: String = env::var("PG_DB_PORT").expect("PG_DB_PORT var not found");
+        let db_user: String = env::var("DB_USER").expect("DB_USER var not found");
+        let db_password: String = env::var("DB_PASSWORD").expect("DB_PASSWORD var not found");
+        let db_name: String = env::var("DB_NAME").expect("DB_NAME var not found");
+
+        #[derive(Clone, Debug, FromRow, PartialEq)]
+        pub struct User {
+            id: Uuid,
+            username: String,
+        }
+
+        let pool: PgPool = match PgPoolOptions::new()
+            .max_connections(1)
+            .connect(&format!(
+                "postgres://{}:{}@{}:{}/{}",
+                db_user, db_password, db_host, db_port, db_name
+            ))
+            .await
+        {
+            Ok(pool) => pool,
+            Err(e) => {
+                panic!("Failed to connect to Postgres: {:?}", e);
+            }
+        };
+
+        let query: QueryBuilder<Postgres> = QueryBuilder::<Postgres>::new(
+            "SELECT id, username FROM test_page_hunter.users ORDER BY username",
+        );
+
+        let mut transaction = pool.begin().await.unwrap();
+
+        let paginator: Paginator<Postgres, User> =
+            Paginator::new(&query, &mut *transaction).await.unwrap();
+        assert_eq!(paginator.get_total(), 100);
+
+        // The memoized total is kept even if the records change after the count.
+        sqlx::query("DELETE FROM test_page_hunter.users WHERE username = 'user1'")
+            .execute(&mut *transaction)
+            .await
+            .unwrap();
+        assert_eq!(query.count(&mut *transaction).await.unwrap(), 99);
+        assert_eq!(paginator.get_total(), 100);
+
+        let page: Page<User> = paginator.fetch_page(&mut *transaction, 2, 3).await.unwrap();
+        assert_eq!(page.get_total(), 100);
+        assert_eq!(page.get_pages(), 34);
+        transaction.rollback().await.unwrap();
+
+        let paginator: Paginator<Postgres, User> = Paginator::new(&query, &pool).await.unwrap();
+        for page in [0, 2, 33] {
+            let fetched: Page<User> = paginator.fetch_page(&pool, page, 3).await.unwrap();
+            let paginated: Page<User> = query.paginate(&pool, page, 3).await.unwrap();
+            assert_eq!(fetched, paginated);
+        }
+
+        let error: PaginationError = paginator.fetch_page(&pool, 34, 3).await.unwrap_err();
+        assert!(error.get_error_kind().is_out_of_range_page());
+    }
+
+    /// Test pagination of a GROUP BY query with a user-supplied count query
+    #[tokio::test]
+    async fn test_pagination_with_count_query() {
+        use sqlx::postgres::PgPoolOptions;
+        use sqlx::{FromRow, PgPool, Postgres, QueryBuilder};
+
+        let db_host: String = env::var("DB_HOST").expect("DB_HOST var not found");
+        let db_port: String = env::var("PG_DB_PORT").expect("PG_DB_PORT var not found");
+        let db_user: String = env::var("DB_USER").expect("DB_USER var not found");
+        let db_password: String = env::var("DB_PASSWORD").expect("DB_PASSWORD var not found");
+        let db_name: String = env::var("DB_NAME").expect("DB_NAME var not found");
+
+        #[derive(Clone, Debug, FromRow)]
+        pub struct UsernameLength {
+            username_length: i32,
+            users: i64,
+        }
+
+        let pool: PgPool = match PgPoolOptions::new()
+            .max_connections(1)
+            .connect(&format!(
+                "postgres://{}:{}@{}:{}/{}",
+                db_user, db_password, db_host, db_port, db_name
+            ))
+            .await
+        {
+            Ok(pool) => pool,
+            Err(e) => {
+                panic!("Failed to connect to Postgres: {:?}", e);
+            }
+        };
+
+        let query: QueryBuilder<Postgres> = QueryBuilder::<Postgres>::new(
+            "SELECT length(username) AS username_length, count(*) AS users FROM test_page_hunter.users GROUP BY length(username) ORDER BY username_length",
+        );
+
+        let lengths: Page<UsernameLength> = query
+            .paginate_with_count(
+                &pool,
+                0,
+                2,
+                "SELECT count(DISTINCT length(username)) FROM test_page_hunter.users",
+            )
+            .await
+            .unwrap();
+        assert_eq!(lengths.get_total(), 3);
+        assert_eq!(lengths.get_pages(), 2);
+        assert_eq!(lengths.get_items()[0].username_length, 5);
+        assert_eq!(lengths.get_items()[0].users, 9);
+        assert_eq!(lengths.get_items()[1].username_length, 6);
+        assert_eq!(lengths.get_items()[1].users, 90);
+
+        // Counting the ungrouped rows gives a total that does not match the grouped records.
+        let error: PaginationError = query
+            .paginate_with_count::<_, UsernameLength>(
+                &pool,
+                1,
+                2,
+                "SELECT count(*) FROM test_page_hunter.users",
+            )
+            .await
+            .unwrap_err();
+        assert!(error.get_error_kind().is_field_value_error());
+
+        let error: PaginationError = query
+            .paginate_with_count::<_, UsernameLength>(&pool, 0, 2, "SELECT count(*) FROM missing")
+            .await
+            .unwrap_err();
+        assert!(error.get_error_kind().is_sqlx_error());
+        assert_eq!(
+            error.get_context(),
+            Some("COUNT query failed for page '0' and size '2'")
+        );
+    }
+
+    /// Test pagination with bound arguments
+    #[tokio::test]
+    async fn test_pagination_with_bound_arguments() {
+        use sqlx::postgres::PgPoolOptions;
+        use sqlx::{FromRow, PgPool, Postgres, QueryBuilder};
+
+        let db_host: String = env::var("DB_HOST").expect("DB_HOST var not found");
+        let db_port: String = env::var("PG_DB_PORT").expect("PG_DB_PORT var not found");
+        let db_user: String = env::var("DB_USER").expect("DB_USER var not found");
+        let db_password: String = env::var("DB_PASSWORD").expect("DB_PASSWORD var not found");
+        let db_name: String = env::var("DB_NAME").expect("DB_NAME var not found");
+
+        #[derive(Clone, Debug, FromRow)]
+        #[allow(dead_code)]
+        pub struct User {
+            username: String,
+        }
+
+        let pool: PgPool = match PgPoolOptions::new()
+            .max_connections(1)
+            .connect(&format!(
+                "postgres://{}:{}@{}:{}/{}",
+                db_user, db_password, db_host, db_port, db_name
+            ))
+            .await
+        {
+            Ok(pool) => pool,
+            Err(e) => {
+                panic!("Failed to connect to Postgres: {:?}", e);
+            }
+        };
+
+        let mut query: QueryBuilder<Postgres> = QueryBuilder::<Postgres>::new(
+            "SELECT username FROM test_page_hunter.users WHERE username ILIKE ",
+        );
+        query.push_bind("USER1%").push(" ORDER BY username");
+
+        let users: Page<User> = query.paginate_bound(&pool, 1, 5).await.unwrap();
+        assert_eq!(users.get_items().len(), 5);
+        assert_eq!(users.get_page(), 1);
+        assert_eq!(users.get_size(), 5);
+        assert_eq!(users.get_pages(), 3);
+        assert_eq!(users.get_total(), 12);
+        assert_eq!(users.get_previous_page(), Some(0));
+        assert_eq!(users.get_next_page(), Some(2));
+
+        assert_eq!(users.get_items()[0].username, "user13");
+        assert_eq!(users.get_items()[4].username, "user17");
+
+        // The bound value is never interpolated in the SQL, so it only matches literally.
+        let mut query: QueryBuilder<Postgres> = QueryBuilder::<Postgres>::new(
+            "SELECT username FROM test_page_hunter.users WHERE username ILIKE ",
+        );
+        query.push_bind("user1' OR '1' = '1");
+
+        let users: Page<User> = query.paginate_bound(&pool, 0, 5).await.unwrap();
+        assert_eq!(users.get_items().len(), 0);
+        assert_eq!(users.get_total(), 0);
+        assert_eq!(users.get_pages(), 1);
+
+        let mut query: QueryBuilder<Postgres> = QueryBuilder::<Postgres>::new(
+            "SELECT username FROM test_page_hunter.users WHERE username ILIKE ",
+        );
+        query.push_bind("user1%");
+
+        let error: PaginationError = query
+            .paginate_bound::<_, User>(&pool, 3, 5)
+            .await
+            .unwrap_err();
+        assert!(error.get_error_kind().is_out_of_range_page());
+    }
+
+    /// Test pagination inside a transaction with concurrent inserts
+    #[tokio::test]
+    async fn test_pagination_in_transaction_with_concurrent_inserts() {
+        use sqlx::postgres::PgPoolOptions;
+        use sqlx::{FromRow, PgPool, Postgres, QueryBuilder};
+
+        let db_host: String = env::var("DB_HOST").expect("DB_HOST var not found");
+        let db_port: String = env::var("PG_DB_PORT").expect("PG_DB_PORT var not found");
+        let db_user: String = env::var("DB_USER").expect("DB_USER var not found");
+        let db_password: String = env::var("DB_PASSWORD").expect("DB_PASSWORD var not found");
+        let db_name: String = env::var("DB_NAME").expect("DB_NAME var not found");
+
+        #[derive(Clone, Debug, FromRow, PartialEq)]
+        pub struct User {
+            username: String,
+        }
+
+        let pool: PgPool = match PgPoolOptions::new()
+            .max_connections(2)
+            .connect(&format!(
+                "postgres://{}:{}@{}:{}/{}",
+                db_user, db_password, db_host, db_port, db_name
+            ))
+            .await
+        {
+            Ok(pool) => pool,
+            Err(e) => {
+                panic!("Failed to connect to Postgres: {:?}", e);
+            }
+        };
+
+        // A copy of the users is used so the concurrent insert does not affect other tests.
+        sqlx::query("DROP TABLE IF EXISTS test_page_hunter.transaction_users")
+            .execute(&pool)
+            .await
+            .unwrap();
+        sqlx::query(
+            "CREATE TABLE test_page_hunter.transaction_users AS SELECT username FROM test_page_hunter.users",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        let query: QueryBuilder<Postgres> = QueryBuilder::<Postgres>::new(
+            "SELECT username FROM test_page_hunter.transaction_users ORDER BY username",
+        );
+
+        let mut transaction = pool.begin().await.unwrap();
+        sqlx::query("SET TRANSACTION ISOLATION LEVEL REPEATABLE READ")
+            .execute(&mut *transaction)
+            .await
+            .unwrap();
+
+        let first: Page<User> = query.paginate(&mut *transaction, 0, 10).await.unwrap();
+        assert_eq!(first.get_total(), 100);
+
+        sqlx::query("INSERT INTO test_page_hunter.transaction_users (username) VALUES ('user101')")
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        // Both the count and the records are read from the snapshot of the transaction.
+        let last: Page<User> = query.paginate(&mut *transaction, 9, 10).await.unwrap();
+        assert_eq!(last.get_total(), 100);
+        assert_eq!(last.get_pages(), 10);
+        assert_eq!(last.get_items().len(), 10);
+        assert_eq!(last.get_next_page(), None);
+        assert_eq!(
+            query.paginate(&mut *transaction, 0, 10).await.unwrap(),
+            first
+        );
+        transaction.commit().await.unwrap();
+
+        let users: Page<User> = query.paginate(&pool, 10, 10).await.unwrap();
+        assert_eq!(users.get_total(), 101);
+        assert_eq!(users.get_items().len(), 1);
+
+        sqlx::query("DROP TABLE test_page_hunter.transaction_users")
+            .execute(&pool)
+            .await
+            .unwrap();
+    }
+
+    /// Test count of a filtered query without fetching a page
+    #[tokio::test]
+    async fn test_count_filtered_query() {
+        use sqlx::postgres::PgPoolOptions;
+        use sqlx::{FromRow, PgPool, Postgres, QueryBuilder};
+
+        let db_host: String = env::var("DB_HOST").expect("DB_HOST var not found");
+        let db_port: String = env::var("PG_DB_PORT").expect("PG_DB_PORT var not found");
+        let db_user: String = env::var("DB_USER").expect("DB_USER var not found");
+        let db_password: String = env::var("DB_PASSWORD").expect("DB_PASSWORD var not found");
+        let db_name: String = env::var("DB_NAME").expect("DB_NAME var not found");
+
+        #[derive(Clone, FromRow)]
+        #[allow(dead_code)]
+        pub struct User {
+            username: String,
+        }
+
+        let pool: PgPool = match PgPoolOptions::new()
+            .max_connections(1)
+            .connect(&format!(
+                "postgres://{}:{}@{}:{}/{}",
+                db_user, db_password, db_host, db_port, db_name
+            ))
+            .await
+        {
+            Ok(pool) => pool,
+            Err(e) => {
+                panic!("Failed to connect to Postgres: {:?}", e);
+            }
+        };
+
+        let query: QueryBuilder<Postgres> = QueryBuilder::<Postgres>::new(
+            "SELECT username FROM test_page_hunter.users WHERE username LIKE 'user1%'",
+        );
+
+        let total: usize = query.count(&pool).await.unwrap();
+        assert_eq!(total, 12);
+
+        let users: Page<User> = query.paginate(&pool, 0, 5).await.unwrap();
+        assert_eq!(users.get_total(), total);
+
+        let error: PaginationError = QueryBuilder::<Postgres>::new("SELECT * FROM missing")
+            .count(&pool)
+            .await
+            .unwrap_err();
+        assert!(error.get_error_kind().is_sqlx_error());
+        assert_eq!(error.get_context(), Some("COUNT query failed"));
+    }
+
+    /// Test pagination with an estimated total
+    #[cfg(feature = "pg-estimated-count")]
+    #[tokio::test]
+    async fn test_pagination_estimated() {
+        use sqlx::postgres::PgPoolOptions;
+        use sqlx::{FromRow, PgPool, Postgres, QueryBuilder};
+
+        let db_host: String = env::var("DB_HOST").expect("DB_HOST var not found");
+        let db_port: String = env::var("PG_DB_PORT").expect("PG_DB_PORT var not found");
+        let db_user: String = env::var("DB_USER").expect("DB_USER var not found");
+        let db_password: String = env::var("DB_PASSWORD").expect("DB_PASSWORD var not found");
+        let db_name: String = env::var("DB_NAME").expect("DB_NAME var not found");
+
+        #[derive(Clone, Debug, FromRow)]
+        #[allow(dead_code)]
+        pub struct User {
+            username: String,
+        }
+
+        let pool: PgPool = match PgPoolOptions::new()
+            .max_connections(1)
+            .connect(&format!(
+                "postgres://{}:{}@{}:{}/{}",
+                db_user, db_password, db_host, db_port, db_name
+            ))
+            .await
+        {
+            Ok(pool) => pool,
+            Err(e) => {
+                panic!("Failed to connect to Postgres: {:?}", e);
+            }
+        };
+
+        let query: QueryBuilder<Postgres> = QueryBuilder::<Postgres>::new(
+            "SELECT username FROM test_page_hunter.users ORDER BY username",
+        );
+
+        let first: Page<User> = query.paginate_estimated(&pool, 0, 10).await.unwrap();
+        assert_eq!(first.get_items().len(), 10);
+        assert!(first.get_total() > 10);
+        assert_eq!(first.get_next_page(), Some(1));
+        assert!(first.verify_fields().is_ok());
+
+        // The total of the last page is exact.
+        let last: Page<User> = query.paginate_estimated(&pool, 9, 10).await.unwrap();
+        assert_eq!(last.get_items().len(), 10);
+        assert_eq!(last.get_total(), 100);
+        assert_eq!(last.get_pages(), 10);
+        assert_eq!(last.get_next_page(), None);
+
+        let error: PaginationError = query
+            .paginate_estimated::<_, User>(&pool, 10, 10)
+            .await
+            .unwrap_err();
+        assert!(error.get_error_kind().is_out_of_range_page());
+
+        let error: PaginationError = query
+            .paginate_estimated::<_, User>(&pool, 0, 0)
+            .await
+            .unwrap_err();
+        assert!(error.get_error_kind().is_field_value_error());
+    }
+
+    /// Test pagination of all the records into a book
+    #[tokio::test]
+    async fn test_paginate_all() {
+        use sqlx::postgres::PgPoolOptions;
+        use sqlx::{FromRow, PgPool, Postgres, QueryBuilder};
+
+        let db_host: String = env::var("DB_HOST").expect("DB_HOST var not found");
+        let db_port: String = env::var("PG_DB_PORT").expect("PG_DB_PORT var not found");
+        let db_user: String = env::var("DB_USER").expect("DB_USER var not found");
+        let db_password: String = env::var("DB_PASSWORD").expect("DB_PASSWORD var not found");
+        let db_name: String = env::var("DB_NAME").expect("DB_NAME var not found");
+
+        #[derive(Clone, Debug, FromRow, PartialEq)]
+        pub struct User {
+            username: String,
+        }
+
+        let pool: PgPool = match PgPoolOptions::new()
+            .max_connections(1)
+            .connect(&format!(
+                "postgres://{}:{}@{}:{}/{}",
+                db_user, db_password, db_host, db_port, db_name
+            ))
+            .await
+        {
+            Ok(pool) => pool,
+            Err(e) => {
+                panic!("Failed to connect to Postgres: {:?}", e);
+            }
+        };
+
+        let query: QueryBuilder<Postgres> = QueryBuilder::<Postgres>::new(
+            "SELECT username FROM test_page_hunter.users ORDER BY username",
+        );
+
+        let book: Book<User> = query.paginate_all(&pool, 7).await.unwrap();
+        let total: usize = query.count(&pool).await.unwrap();
+        assert_eq!(book.get_sheets().len(), total.div_ceil(7));
+        assert_eq!(book.get_sheets().len(), 15);
+        assert!(book.verify().is_ok());
+
+        let users: Vec<User> = sqlx::query_as::<_, User>(
+            "SELECT username FROM test_page_hunter.users ORDER BY username",
+        )
+        .fetch_all(&pool)
+        .await
+        .unwrap();
+        let items: Vec<User> = book
+            .into_iter()
+            .flat_map(|sheet| sheet.into_iter())
+            .collect();
+        assert_eq!(items, users);
+
+        let error: PaginationError = query.paginate_all::<_, User>(&pool, 0).await.unwrap_err();
+        assert!(error.get_error_kind().is_field_value_error());
+    }
+
+    /// Test pagination of all the records into a stream of pages
+    #[tokio::test]
+    async fn test_stream_pages() {
+        use futures_util::StreamExt;
+        use sqlx::postgres::PgPoolOptions;
+        use sqlx::{FromRow, PgPool, Postgres, QueryBuilder};
+
+        let db_host: String = env::var("DB_HOST").expect("DB_HOST var not found");
+        let db_port: String = env::var("PG_DB_PORT").expect("PG_DB_PORT var not found");
+        let db_user: String = env::var("DB_USER").expect("DB_USER var not found");
+        let db_password: String = env::var("DB_PASSWORD").expect("DB_PASSWORD var not found");
+        let db_name: String = env::var("DB_NAME").expect("DB_NAME var not found");
+
+        #[derive(Clone, Debug, FromRow, PartialEq)]
+        pub struct User {
+            username: String,
+        }
+
+        let pool: PgPool = match PgPoolOptions::new()
+            .max_connections(1)
+            .connect(&format!(
+                "postgres://{}:{}@{}:{}/{}",
+                db_user, db_password, db_host, db_port, db_name
+            ))
+            .await
+        {
+            Ok(pool) => pool,
+            Err(e) => {
+                panic!("Failed to connect to Postgres: {:?}", e);
+            }
+        };
+
+        let query: QueryBuilder<Postgres> = QueryBuilder::<Postgres>::new(
+            "SELECT username FROM test_page_hunter.users ORDER BY username",
+        );
+        let book: Book<User> = query.paginate_all(&pool, 30).await.unwrap();
+
+        let sheets: Vec<Page<User>> = query
+            .stream_pages(&pool, 30)
+            .map(|sheet| sheet.unwrap())
+            .collect()
+            .await;
+        assert_eq!(sheets.len(), 4);
+        for (index, sheet) in sheets.iter().enumerate() {
+            assert_eq!(sheet.get_page(), index);
+            assert_eq!(sheet.get_total(), 100);
+        }
+        assert_eq!(&sheets, book.get_sheets());
+
+        let query: QueryBuilder<Postgres> =
+            QueryBuilder::<Postgres>::new("SELECT username FROM test_page_hunter.users");
+        let results: Vec<PaginationResult<Page<User>>> =
+            query.stream_pages(&pool, 0).collect().await;
+        assert_eq!(results.len(), 1);
+        assert!(results[0]
+            .as_ref()
+            .unwrap_err()
+            .get_error_kind()
+            .is_field_value_error());
+
+        let query: QueryBuilder<Postgres> =
+            QueryBuilder::<Postgres>::new("SELECT username FROM test_page_hunter.users");
+        let mut stream = Box::pin(query.stream_pages::<User>(&pool, 30));
+        for _ in 0..4 {
+            assert!(stream.next().await.unwrap().is_ok());
+        }
+        pool.close().await;
+        assert!(stream.next().await.is_none());
+    }
+
+    /// Test pagination of the records into a stream of pages stopped early by a time budget
+    #[tokio::test]
+    async fn test_stream_pages_bounded() {
+        use futures_util::StreamExt;
+        use sqlx::postgres::PgPoolOptions;
+        use sqlx::{FromRow, PgPool, Postgres, QueryBuilder};
+        use std::time::Duration;
+
+        let db_host: String = env::var("DB_HOST").expect("DB_HOST var not found");
+        let db_port: String = env::var("PG_DB_PORT").expect("PG_DB_PORT var not found");
+        let db_user: String = env::var("DB_USER").expect("DB_USER var not found");
+        let db_password: String = env::var("DB_PASSWORD").expect("DB_PASSWORD var not found");
+        let db_name: String = env::var("DB_NAME").expect("DB_NAME var not found");
+
+        #[derive(Clone, Debug, FromRow, PartialEq)]
+        pub struct User {
+            username: String,
+        }
+
+        let pool: PgPool = match PgPoolOptions::new()
+            .max_connections(1)
+            .connect(&format!(
+                "postgres://{}:{}@{}:{}/{}",
+                db_user, db_password, db_host, db_port, db_name
+            ))
+            .await
+        {
+            Ok(pool) => pool,
+            Err(e) => {
+                panic!("Failed to connect to Postgres: {:?}", e);
+            }
+        };
+
+        let query: QueryBuilder<Postgres> = QueryBuilder::<Postgres>::new(
+            "SELECT username FROM test_page_hunter.users ORDER BY username",
+        );
+        let sheets: Vec<(Page<User>, bool)> = query
+            .stream_pages_bounded(&pool, 30, Duration::ZERO)
+            .map(|sheet| sheet.unwrap())
+            .collect()
+            .await;
+        assert_eq!(sheets.len(), 1);
+        let (sheet, stopped_early): &(Page<User>, bool) = &sheets[0];
+        assert!(stopped_early);
+        assert!(sheet.verify_fields().is_ok());
+        assert_eq!(sheet.get_page(), 0);
+        assert_eq!(sheet.get_total(), 100);
+        assert_eq!(sheet.get_next_page(), Some(1));
+
+        let query: QueryBuilder<Postgres> = QueryBuilder::<Postgres>::new(
+            "SELECT username FROM test_page_hunter.users ORDER BY username",
+        );
+        let sheets: Vec<(Page<User>, bool)> = query
+            .stream_pages_bounded(&pool, 30, Duration::from_secs(60))
+            .map(|sheet| sheet.unwrap())
+            .collect()
+            .await;
+        assert_eq!(sheets.len(), 4);
+        for (index, (sheet, stopped_early)) in sheets.iter().enumerate() {
+            assert!(!stopped_early);
+            assert_eq!(sheet.get_page(), index);
+            assert!(sheet.verify_fields().is_ok());
+        }
+    }
+
+    /// Test pagination from a plain SQL query string.
+    #[tokio::test]
+    async fn test_paginate_sql() {
+        use sqlx::pool::PoolConnection;
+        use sqlx::postgres::PgPoolOptions;
+        use sqlx::{FromRow, PgPool, Postgres, QueryBuilder};
+
+        let db_host: String = env::var("DB_HOST").expect("DB_HOST var not found");
+        let db_port: String = env::var("PG_DB_PORT").expect("PG_DB_PORT var not found");
+        let db_user: String = env::var("DB_USER").expect("DB_USER var not found");
+        let db_password: String = env::var("DB_PASSWORD").expect("DB_PASSWORD var not found");
+        let db_name: String = env::var("DB_NAME").expect("DB_NAME var not found");
+
+        #[derive(Clone, Debug, FromRow, PartialEq)]
+        pub struct User {
+            username: String,
+        }
+
+        let pool: PgPool = match PgPoolOptions::new()
+            .max_connections(1)
+            .connect(&format!(
+                "postgres://{}:{}@{}:{}/{}",
+                db_user, db_password, db_host, db_port, db_name
+            ))
+            .await
+        {
+            Ok(pool) => pool,
+            Err(e) => {
+                panic!("Failed to connect to Postgres: {:?}", e);
+            }
+        };
+
+        let sql: &str = "SELECT username FROM test_page_hunter.users ORDER BY username";
+
+        let mut conn: PoolConnection<Postgres> = pool.acquire().await.unwrap();
+        let page: Page<User> = paginate_sql::<Postgres, User>(sql, &mut *conn, 2, 10)
+            .await
+            .unwrap();
+        drop(conn);
+
+        let expected_page: Page<User> = QueryBuilder::<Postgres>::new(sql)
+            .paginate(&pool, 2, 10)
+            .await
+            .unwrap();
+        assert_eq!(page, expected_page);
+        assert_eq!(page.get_total(), 100);
+        assert_eq!(page.get_pages(), 10);
+        assert_eq!(page.get_items().len(), 10);
+
+        let error: PaginationError = paginate_sql::<Postgres, User>(sql, &pool, 10, 10)
+            .await
+            .unwrap_err();
+        assert!(error.get_error_kind().is_out_of_range_page());
+    }
+
+    /// Test clamped pagination with a page index beyond the last page.
+    #[tokio::test]
+    async fn test_pagination_clamped_beyond_range() {
+        use sqlx::postgres::PgPoolOptions;
+        use sqlx::{FromRow, PgPool, Postgres, QueryBuilder};
+
+        let db_host: String = env::var("DB_HOST").expect("DB_HOST var not found");
+        let db_port: String = env::var("PG_DB_PORT").expect("PG_DB_PORT var not found");
+        let db_user: String = env::var("DB_USER").expect("DB_USER var not found");
+        let db_password: String = env::var("DB_PASSWORD").expect("DB_PASSWORD var not found");
+        let db_name: String = env::var("DB_NAME").expect("DB_NAME var not found");
+
+        #[derive(Clone, Debug, FromRow, PartialEq)]
+        pub struct User {
+            username: String,
+        }
+
+        let pool: PgPool = match PgPoolOptions::new()
+            .max_connections(1)
+            .connect(&format!(
+                "postgres://{}:{}@{}:{}/{}",
+                db_user, db_password, db_host, db_port, db_name
+            ))
+            .await
+        {
+            Ok(pool) => pool,
+            Err(e) => {
+                panic!("Failed to connect to Postgres: {:?}", e);
+            }
+        };
+
+        let query: QueryBuilder<Postgres> = QueryBuilder::<Postgres>::new(
+            "SELECT username FROM test_page_hunter.users ORDER BY username",
+        );
+
+        let page: Page<User> = query.paginate_clamped(&pool, 12, 10).await.unwrap();
+        assert!(page.get_items().is_empty());
+        assert_eq!(page.get_page(), 12);
+        assert_eq!(page.get_size(), 10);
+        assert_eq!(page.get_total(), 100);
+        assert_eq!(page.get_pages(), 10);
+        assert_eq!(page.get_previous_page(), Some(9));
+        assert_eq!(page.get_next_page(), None);
+        assert!(page.verify_fields().is_ok());
+
+        let last_page: Page<User> = query.paginate_clamped(&pool, 9, 10).await.unwrap();
+        assert_eq!(last_page, query.paginate(&pool, 9, 10).await.unwrap());
+        assert_eq!(last_page.get_items().len(), 10);
+    }
+
+    /// Test ordered pagination returns adjacent pages without overlapping records.
+    #[tokio::test]
+    async fn test_pagination_ordered() {
+        use sqlx::postgres::PgPoolOptions;
+        use sqlx::{FromRow, PgPool, Postgres, QueryBuilder};
+        use uuid::Uuid;
+
+        let db_host: String = env::var("DB_HOST").expect("DB_HOST var not found");
+        let db_port: String = env::var("PG_DB_PORT").expect("PG_DB_PORT var not found");
+        let db_user: String = env::var("DB_USER").expect("DB_USER var not found");
+        let db_password: String = env::var("DB_PASSWORD").expect("DB_PASSWORD var not found");
+        let db_name: String = env::var("DB_NAME").expect("DB_NAME var not found");
+
+        #[derive(Clone, Debug, FromRow, PartialEq)]
+        pub struct User {
+            id: Uuid,
+            username: String,
+        }
+
+        let pool: PgPool = match PgPoolOptions::new()
+            .max_connections(1)
+            .connect(&format!(
+                "postgres://{}:{}@{}:{}/{}",
+                db_user, db_password, db_host, db_port, db_name
+            ))
+            .await
+        {
+            Ok(pool) => pool,
+            Err(e) => {
+                panic!("Failed to connect to Postgres: {:?}", e);
+            }
+        };
+
+        let query: QueryBuilder<Postgres> =
+            QueryBuilder::<Postgres>::new("SELECT id, username FROM test_page_hunter.users");
+
+        assert_eq!(
+            query.paginated_sql(1, 10).unwrap(),
+            "WITH temp_table AS (SELECT id, username FROM test_page_hunter.users) SELECT * from temp_table LIMIT 10 OFFSET 10;"
+        );
+
+        let first_page: Page<User> = query.paginate_ordered(&pool, 0, 10, "id").await.unwrap();
+        let second_page: Page<User> = query.paginate_ordered(&pool, 1, 10, "id").await.unwrap();
+        assert_eq!(first_page.get_total(), 100);
+        assert_eq!(first_page.get_items().len(), 10);
+        assert_eq!(second_page.get_items().len(), 10);
+        assert!(first_page
+            .get_items()
+            .iter()
+            .all(|user| !second_page.get_items().contains(user)));
+        assert!(first_page.get_items().last().unwrap().id < second_page.get_items()[0].id);
+
+        let mut ids: Vec<Uuid> = Vec::new();
+        for page in 0..first_page.get_pages() {
+            let users: Page<User> = query.paginate_ordered(&pool, page, 10, "id").await.unwrap();
+            ids.extend(users.get_items().iter().map(|user| user.id));
+        }
+        let mut sorted_ids: Vec<Uuid> = ids.clone();
+        sorted_ids.sort();
+        sorted_ids.dedup();
+        assert_eq!(ids, sorted_ids);
+        assert_eq!(ids.len(), 100);
+    }
+
+    /// Test pagination of a query with its own WITH clause, reusing the name of the wrapping CTE
+    #[tokio::test]
+    async fn test_pagination_query_with_cte() {
+        use sqlx::postgres::PgPoolOptions;
+        use sqlx::{FromRow, PgPool, Postgres, QueryBuilder};
+        use uuid::Uuid;
+
+        let db_host: String = env::var("DB_HOST").expect("DB_HOST var not found");
+        let db_port: String = env::var("PG_DB_PORT").expect("PG_DB_PORT var not found");
+        let db_user: String = env::var("DB_USER").expect("DB_USER var not found");
+        let db_password: String = env::var("DB_PASSWORD").expect("DB_PASSWORD var not found");
+        let db_name: String = env::var("DB_NAME").expect("DB_NAME var not found");
+
+        #[derive(Clone, Debug, FromRow, PartialEq)]
+        pub struct User {
+            id: Uuid,
+            username: String,
+        }
+
+        let pool: PgPool = match PgPoolOptions::new()
+            .max_connections(1)
+            .connect(&format!(
+                "postgres://{}:{}@{}:{}/{}",
+                db_user, db_password, db_host, db_port, db_name
+            ))
+            .await
+        {
+            Ok(pool) => pool,
+            Err(e) => {
+                panic!("Failed to connect to Postgres: {:?}", e);
+            }
+        };
+
+        let query: QueryBuilder<Postgres> = QueryBuilder::<Postgres>::new(
+            "WITH temp_table AS (SELECT id, username FROM test_page_hunter.users WHERE username LIKE 'user1%') SELECT id, username FROM temp_table ORDER BY username",
+        );
+
+        assert_eq!(query.count(&pool).await.unwrap(), 12);
+
+        let page: Page<User> = query.paginate(&pool, 1, 5).await.unwrap();
+        assert_eq!(page.get_total(), 12);
+        assert_eq!(page.get_pages(), 3);
+        assert_eq!(page.get_items().len(), 5);
+        assert!(page
+            .get_items()
+            .iter()
+            .all(|user| user.username.starts_with("user1")));
+    }
+}
+
+#[cfg(feature = "mysql-sqlx")]
+#[cfg(test)]
+pub mod test_mysql_pagination {
+    use page_hunter::*;
+    use std::env;
+
+    /// Test successful pagination
+    #[tokio::test]
+    async fn test_pagination_success() {
+        use sqlx::mysql::MySqlPoolOptions;
+        use sqlx::{FromRow, MySql, MySqlPool, QueryBuilder};
+        use time::OffsetDateTime;
+
+        let db_host: String = env::var("DB_HOST").expect("DB_HOST var not found");
+        let db_port: String = env::var("MYSQL_DB_PORT").expect("MYSQL_DB_PORT var not found");
+        let db_user: String = env::var("DB_USER").expect("DB_USER var not found");
+        let db_password: String = env::var("DB_PASSWORD").expect("DB_PASSWORD var not found");
+        let db_name: String = env::var("DB_NAME").expect("DB_NAME var not found");
+
+        #[derive(Clone, FromRow)]
+        #[allow(dead_code)]
+        pub struct States {
+            id: i64,
+            country_name: String,
+            name: String,
+            created_at: OffsetDateTime,
+            updated_at: Option<OffsetDateTime>,
+        }
+
+        let pool: MySqlPool = match MySqlPoolOptions::new()
+            .max_connections(1)
+            .connect(&format!(
+                "mysql://{}:{}@{}:{}/{}",
+                db_user, db_password, db_host, db_port, db_name
+            ))
+            .await
+        {
+            Ok(pool) => pool,
+            Err(e) => {
+                panic!("Failed to connect to MySQL: {:?}", e);
+            }
+        };
+
+        let query: QueryBuilder<MySql> = QueryBuilder::<MySql>::new("SELECT * FROM states");
+
+        let users_pagination: PaginationResult<Page<States>> = query.paginate(&pool, 4, 7).await;
+        assert!(users_pagination.is_ok());
+
+        let users: Page<States> = users_pagination.unwrap();
+
+        assert_eq!(users.get_items().len(), 7);
+        assert_eq!(users.get_page(), 4);
+        assert_eq!(users.get_size(), 7);
+        assert_eq!(users.get_pages(), 15);
+        assert_eq!(users.get_total(), 100);
+        assert_eq!(users.get_previous_page(), Some(3));
+        assert_eq!(users.get_next_page(), Some(5));
+
+        assert_eq!(users.get_items()[0].country_name, "Country 29");
+        assert_eq!(users.get_items()[1].country_name, "Country 30");
+        assert_eq!(users.get_items()[2].country_name, "Country 31");
+        assert_eq!(users.get_items()[3].country_name, "Country 32");
+        assert_eq!(users.get_items()[4].country_name, "Country 33");
+        assert_eq!(users.get_items()[5].country_name, "Country 34");
+        assert_eq!(users.get_items()[6].country_name, "Country 35");
+
+        assert_eq!(users.get_items()[0].name, "State 29");
+        assert_eq!(users.get_items()[1].name, "State 30");
+        assert_eq!(users.get_items()[2].name, "State 31");
+        assert_eq!(users.get_items()[3].name, "State 32");
+        assert_eq!(users.get_items()[4].name, "State 33");
+        assert_eq!(users.get_items()[5].name, "State 34");
+        assert_eq!(users.get_items()[6].name, "State 35");
+    }
+
+    /// Test database error when is not possible to get total by invalid query
+    #[tokio::test]
+    async fn test_error_fetching_total_records() {
+        use sqlx::mysql::MySqlPoolOptions;
+        use sqlx::{FromRow, MySql, MySqlPool, QueryBuilder};
+        use time::OffsetDateTime;
+
+        let db_host: String = env::var("DB_HOST").expect("DB_HOST var not found");
+        let db_port: String = env::var("MYSQL_DB_PORT").expect("MYSQL_DB_PORT var not found");
+        let db_user: String = env::var("DB_USER").expect("DB_USER var not found");
+        let db_password: String = env::var("DB_PASSWORD").expect("DB_PASSWORD var not found");
+        let db_name: String = env::var("DB_NAME").expect("DB_NAME var not found");
+
+        #[derive(Clone, Debug, FromRow)]
+        #[allow(dead_code)]
+        pub struct States {
+            id: i64,
+            country_name: String,
+            name: String,
+            created_at: OffsetDateTime,
+            updated_at: Option<OffsetDateTime>,
+        }
+
+        let pool: MySqlPool = match MySqlPoolOptions::new()
+            .max_connections(1)
+            .connect(&format!(
+                "mysql://{}:{}@{}:{}/{}",
+                db_user, db_password, db_host, db_port, db_name
+            ))
+            .await
+        {
+            Ok(pool) => pool,
+            Err(e) => {
+                panic!("Failed to connect to MySQL: {:?}", e);
+            }
+        };
+
+        let query: QueryBuilder<MySql> = QueryBuilder::<MySql>::new("SELECT * FROM states;");
+
+        let users_pagination: PaginationResult<Page<States>> = query.paginate(&pool, 4, 7).await;
+        assert!(users_pagination.is_err());
+
+        let error: String = users_pagination.unwrap_err().to_string();
+        assert_eq!(
+            error,
+            "SQLX ERROR- error returned from database: 1064 (42000): You have an error in your SQL syntax; check the manual that corresponds to your MySQL server version for the right syntax to use near ';) as temp_table' at line 1 (COUNT query failed for page '4' and size '7')"
+                .to_string(),
+        )
+    }
+
+    /// Test from row error when is not possible to parse the result
+    #[tokio::test]
+    async fn test_from_row_error() {
+        use sqlx::mysql::MySqlPoolOptions;
+        use sqlx::{FromRow, MySql, MySqlPool, QueryBuilder};
+        use time::OffsetDateTime;
+
+        let db_host: String = env::var("DB_HOST").expect("DB_HOST var not found");
+        let db_port: String = env::var("MYSQL_DB_PORT").expect("MYSQL_DB_PORT var not found");
+        let db_user: String = env::var("DB_USER").expect("DB_USER var not found");
+        let db_password: String = env::var("DB_PASSWORD").expect("DB_PASSWORD var not found");
+        let db_name: String = env::var("DB_NAME").expect("DB_NAME var not found");
+
+        #[derive(Clone, Debug, FromRow)]
+        #[allow(dead_code)]
+        pub struct States {
+            id: i64,
+            country_name: String,
+            name: String,
+            extension: i32,
+            created_at: OffsetDateTime,
+            updated_at: Option<OffsetDateTime>,
+        }
+
+        let pool: MySqlPool = match MySqlPoolOptions::new()
+            .max_connections(1)
+            .connect(&format!(
+                "mysql://{}:{}@{}:{}/{}",
+                db_user, db_password, db_host, db_port, db_name
+            ))
+            .await
+        {
+            Ok(pool) => pool,
+            Err(e) => {
+                panic!("Failed to connect to MySQL: {:?}", e);
+            }
+        };
+
+        let query: QueryBuilder<MySql> = QueryBuilder::<MySql>::new("SELECT * FROM states");
+
+        let users_pagination: PaginationResult<Page<States>> = query.paginate(&pool, 4, 7).await;
+        assert!(users_pagination.is_err());
+
+        let error: PaginationError = users_pagination.unwrap_err();
+        assert!(error.get_error_kind().is_from_row_error());
+        assert!(!error.get_error_kind().is_sqlx_error());
+        assert_eq!(
+            error.to_string(),
+            "FROM ROW ERROR- no column found for name: extension".to_string(),
+        )
+    }
+
+    /// Test pagination with invalid page
+    #[tokio::test]
+    async fn test_pagination_invalid_page() {
+        use sqlx::mysql::MySqlPoolOptions;
+        use sqlx::{FromRow, MySql, MySqlPool, QueryBuilder};
+        use time::OffsetDateTime;
+
+        let db_host: String = env::var("DB_HOST").expect("DB_HOST var not found");
+        let db_port: String = env::var("MYSQL_DB_PORT").expect("MYSQL_DB_PORT var not found");
+        let db_user: String = env::var("DB_USER").expect("DB_USER var not found");
+        let db_password: String = env::var("DB_PASSWORD").expect("DB_PASSWORD var not found");
+        let db_name: String = env::var("DB_NAME").expect("DB_NAME var not found");
+
+        #[derive(Clone, Debug, FromRow)]
+        #[allow(dead_code)]
+        pub struct States {
+            id: i64,
+            country_name: String,
+            name: String,
+            created_at: OffsetDateTime,
+            updated_at: Option<OffsetDateTime>,
+        }
+
+        let pool: MySqlPool = match MySqlPoolOptions::new()
+            .max_connections(1)
+            .connect(&format!(
+                "mysql://{}:{}@{}:{}/{}",
+                db_user, db_password, db_host, db_port, db_name
+            ))
+            .await
+        {
+            Ok(pool) => pool,
+            Err(e) => {
+                panic!("Failed to connect to MySQL: {:?}", e);
+            }
+        };
+
+        let query: QueryBuilder<MySql> = QueryBuilder::<MySql>::new("SELECT * FROM states");
+
+        let users_pagination: PaginationResult<Page<States>> = query.paginate(&pool, 5, 30).await;
+        assert!(users_pagination.is_err());
+
         let error: String = users_pagination.unwrap_err().to_string();
         assert_eq!(
             error,
-            "SQLX ERROR- no column found for name: age".to_string(),
+            "OUT OF RANGE PAGE ERROR- Page index '5' exceeds total pages '4'".to_string(),
         )
     }
 
-    /// Test pagination with invalid page
+    /// Test count matches the total of a paginated result
+    #[tokio::test]
+    async fn test_count_matches_pagination_total() {
+        use sqlx::mysql::MySqlPoolOptions;
+        use sqlx::{FromRow, MySql, MySqlPool, QueryBuilder};
+        use time::OffsetDateTime;
+
+        let db_host: String = env::var("DB_HOST").expect("DB_HOST var not found");
+        let db_port: String = env::var("MYSQL_DB_PORT").expect("MYSQL_DB_PORT var not found");
+        let db_user: String = env::var("DB_USER").expect("DB_USER var not found");
+        let db_password: String = env::var("DB_PASSWORD").expect("DB_PASSWORD var not found");
+        let db_name: String = env::var("DB_NAME").expect("DB_NAME var not found");
+
+        #[derive(Clone, FromRow)]
+        #[allow(dead_code)]
+        pub struct States {
+            id: i64,
+            country_name: String,
+            name: String,
+            created_at: OffsetDateTime,
+            updated_at: Option<OffsetDateTime>,
+        }
+
+        let pool: MySqlPool = match MySqlPoolOptions::new()
+            .max_connections(1)
+            .connect(&format!(
+                "mysql://{}:{}@{}:{}/{}",
+                db_user, db_password, db_host, db_port, db_name
+            ))
+            .await
+        {
+            Ok(pool) => pool,
+            Err(e) => {
+                panic!("Failed to connect to MySQL: {:?}", e);
+            }
+        };
+
+        let query: QueryBuilder<MySql> = QueryBuilder::<MySql>::new("SELECT * FROM states");
+
+        let states_count: PaginationResult<usize> = query.count(&pool).await;
+        assert!(states_count.is_ok());
+
+        let states_pagination: PaginationResult<Page<States>> = query.paginate(&pool, 4, 7).await;
+        assert!(states_pagination.is_ok());
+
+        assert_eq!(states_count.unwrap(), 100);
+        assert_eq!(states_pagination.unwrap().get_total(), 100);
+    }
+
+    /// Test pagination inside a transaction
     #[tokio::test]
-    async fn test_pagination_invalid_page() {
-        use sqlx::postgres::PgPoolOptions;
-        use sqlx::{FromRow, PgPool, Postgres, QueryBuilder};
+    async fn test_pagination_in_transaction() {
+        use sqlx::mysql::MySqlPoolOptions;
+        use sqlx::{FromRow, MySql, MySqlPool, QueryBuilder, Transaction};
         use time::OffsetDateTime;
-        use uuid::Uuid;
 
         let db_host: String = env::var("DB_HOST").expect("DB_HOST var not found");
-        let db_port: String = env::var("PG_DB_PORT").expect("PG_DB_PORT var not found");
+        let db_port: String = env::var("MYSQL_DB_PORT").expect("MYSQL_DB_PORT var not found");
         let db_user: String = env::var("DB_USER").expect("DB_USER var not found");
         let db_password: String = env::var("DB_PASSWORD").expect("DB_PASSWORD var not found");
         let db_name: String = env::var("DB_NAME").expect("DB_NAME var not found");
 
-        #[derive(Clone, Debug, FromRow)]
+        #[derive(Clone, FromRow)]
         #[allow(dead_code)]
-        pub struct User {
-            id: Uuid,
-            username: String,
-            hashed_password: String,
-            is_active: bool,
+        pub struct States {
+            id: i64,
+            country_name: String,
+            name: String,
             created_at: OffsetDateTime,
             updated_at: Option<OffsetDateTime>,
         }
 
-        let pool: PgPool = match PgPoolOptions::new()
+        let pool: MySqlPool = match MySqlPoolOptions::new()
             .max_connections(1)
             .connect(&format!(
-                "postgres://{}:{}@{}:{}/{}",
+                "mysql://{}:{}@{}:{}/{}",
                 db_user, db_password, db_host, db_port, db_name
             ))
             .await
         {
             Ok(pool) => pool,
             Err(e) => {
-                panic!("Failed to connect to Postgres: {:?}", e);
+                panic!("Failed to connect to MySQL: {:?}", e);
             }
         };
 
-        let query: QueryBuilder<Postgres> =
-            QueryBuilder::<Postgres>::new("SELECT * FROM test_page_hunter.users");
+        let mut transaction: Transaction<MySql> = pool.begin().await.unwrap();
 
-        let users_pagination: PaginationResult<Page<User>> = query.paginate(&pool, 5, 30).await;
-        assert!(users_pagination.is_err());
+        let query: QueryBuilder<MySql> = QueryBuilder::<MySql>::new("SELECT * FROM states");
 
-        let error: String = users_pagination.unwrap_err().to_string();
-        assert_eq!(
-            error,
-            "FIELD VALUE ERROR- Page index '5' exceeds total pages '4'".to_string(),
-        )
+        let states_pagination: PaginationResult<Page<States>> =
+            query.paginate(&mut transaction, 14, 7).await;
+        assert!(states_pagination.is_ok());
+
+        let states_count: PaginationResult<usize> = query.count(&mut *transaction).await;
+        assert!(states_count.is_ok());
+
+        transaction.rollback().await.unwrap();
+
+        let states: Page<States> = states_pagination.unwrap();
+        assert_eq!(states.get_total(), states_count.unwrap());
+        assert_eq!(states.get_items().len(), 2);
+        assert_eq!(states.get_pages(), 15);
     }
-}
 
-#[cfg(feature = "mysql-sqlx")]
-#[cfg(test)]
-pub mod test_mysql_pagination {
-    use page_hunter::*;
-    use std::env;
+    /// Test generated SQL omits OFFSET for the first page
+    #[test]
+    fn test_paginated_sql() {
+        use sqlx::{MySql, QueryBuilder};
 
-    /// Test successful pagination
+        let query: QueryBuilder<MySql> = QueryBuilder::<MySql>::new("SELECT * FROM states");
+
+        assert_eq!(
+            query.paginated_sql(0, 7).unwrap(),
+            "SELECT * FROM states LIMIT 7;"
+        );
+        assert_eq!(
+            query.paginated_sql(4, 7).unwrap(),
+            "SELECT * FROM states LIMIT 7 OFFSET 28;"
+        );
+        assert!(query
+            .paginated_sql(usize::MAX, 7)
+            .unwrap_err()
+            .get_error_kind()
+            .is_field_value_error());
+    }
+
+    /// Test database error when is not possible to fetch the records by invalid query
     #[tokio::test]
-    async fn test_pagination_success() {
+    async fn test_error_fetching_records() {
         use sqlx::mysql::MySqlPoolOptions;
         use sqlx::{FromRow, MySql, MySqlPool, QueryBuilder};
         use time::OffsetDateTime;
@@ -254,7 +1822,7 @@ pub mod test_mysql_pagination {
         let db_password: String = env::var("DB_PASSWORD").expect("DB_PASSWORD var not found");
         let db_name: String = env::var("DB_NAME").expect("DB_NAME var not found");
 
-        #[derive(Clone, FromRow)]
+        #[derive(Clone, Debug, FromRow)]
         #[allow(dead_code)]
         pub struct States {
             id: i64,
@@ -278,44 +1846,26 @@ pub mod test_mysql_pagination {
             }
         };
 
-        let query: QueryBuilder<MySql> = QueryBuilder::<MySql>::new("SELECT * FROM states");
-
-        let users_pagination: PaginationResult<Page<States>> = query.paginate(&pool, 4, 7).await;
-        assert!(users_pagination.is_ok());
-
-        let users: Page<States> = users_pagination.unwrap();
-
-        assert_eq!(users.get_items().len(), 7);
-        assert_eq!(users.get_page(), 4);
-        assert_eq!(users.get_size(), 7);
-        assert_eq!(users.get_pages(), 15);
-        assert_eq!(users.get_total(), 100);
-        assert_eq!(users.get_previous_page(), Some(3));
-        assert_eq!(users.get_next_page(), Some(5));
+        // The LIMIT clause is valid inside the count subquery, but duplicated in the fetch query.
+        let query: QueryBuilder<MySql> =
+            QueryBuilder::<MySql>::new("SELECT * FROM states LIMIT 50");
 
-        assert_eq!(users.get_items()[0].country_name, "Country 29");
-        assert_eq!(users.get_items()[1].country_name, "Country 30");
-        assert_eq!(users.get_items()[2].country_name, "Country 31");
-        assert_eq!(users.get_items()[3].country_name, "Country 32");
-        assert_eq!(users.get_items()[4].country_name, "Country 33");
-        assert_eq!(users.get_items()[5].country_name, "Country 34");
-        assert_eq!(users.get_items()[6].country_name, "Country 35");
+        let states_pagination: PaginationResult<Page<States>> = query.paginate(&pool, 4, 7).await;
+        assert!(states_pagination.is_err());
 
-        assert_eq!(users.get_items()[0].name, "State 29");
-        assert_eq!(users.get_items()[1].name, "State 30");
-        assert_eq!(users.get_items()[2].name, "State 31");
-        assert_eq!(users.get_items()[3].name, "State 32");
-        assert_eq!(users.get_items()[4].name, "State 33");
-        assert_eq!(users.get_items()[5].name, "State 34");
-        assert_eq!(users.get_items()[6].name, "State 35");
+        let error: PaginationError = states_pagination.unwrap_err();
+        assert!(error.get_error_kind().is_sqlx_error());
+        assert_eq!(
+            error.get_context(),
+            Some("FETCH query failed for page '4' and size '7'")
+        );
     }
 
-    /// Test database error when is not possible to get total by invalid query
+    /// Test pagination rejects a zero page size before querying the database
     #[tokio::test]
-    async fn test_error_fetching_total_records() {
+    async fn test_pagination_zero_size() {
         use sqlx::mysql::MySqlPoolOptions;
         use sqlx::{FromRow, MySql, MySqlPool, QueryBuilder};
-        use time::OffsetDateTime;
 
         let db_host: String = env::var("DB_HOST").expect("DB_HOST var not found");
         let db_port: String = env::var("MYSQL_DB_PORT").expect("MYSQL_DB_PORT var not found");
@@ -327,10 +1877,7 @@ pub mod test_mysql_pagination {
         #[allow(dead_code)]
         pub struct States {
             id: i64,
-            country_name: String,
             name: String,
-            created_at: OffsetDateTime,
-            updated_at: Option<OffsetDateTime>,
         }
 
         let pool: MySqlPool = match MySqlPoolOptions::new()
@@ -347,25 +1894,25 @@ pub mod test_mysql_pagination {
             }
         };
 
-        let query: QueryBuilder<MySql> = QueryBuilder::<MySql>::new("SELECT * FROM states;");
+        let query: QueryBuilder<MySql> = QueryBuilder::<MySql>::new("SELECT id, name FROM states");
 
-        let users_pagination: PaginationResult<Page<States>> = query.paginate(&pool, 4, 7).await;
-        assert!(users_pagination.is_err());
+        let states_pagination: PaginationResult<Page<States>> = query.paginate(&pool, 0, 0).await;
+        assert!(states_pagination.is_err());
 
-        let error: String = users_pagination.unwrap_err().to_string();
+        let error: PaginationError = states_pagination.unwrap_err();
+        assert!(error.get_error_kind().is_field_value_error());
         assert_eq!(
-            error,
-            "SQLX ERROR- error returned from database: 1064 (42000): You have an error in your SQL syntax; check the manual that corresponds to your MySQL server version for the right syntax to use near ';) as temp_table' at line 1"
-                .to_string(),
-        )
+            error.to_string(),
+            "FIELD VALUE ERROR- Page size must be greater than zero"
+        );
     }
 
-    /// Test from row error when is not possible to parse the result
+    /// Test bounded pagination stops early when the budget is exhausted
     #[tokio::test]
-    async fn test_from_row_error() {
+    async fn test_pagination_bounded() {
         use sqlx::mysql::MySqlPoolOptions;
         use sqlx::{FromRow, MySql, MySqlPool, QueryBuilder};
-        use time::OffsetDateTime;
+        use std::time::Duration;
 
         let db_host: String = env::var("DB_HOST").expect("DB_HOST var not found");
         let db_port: String = env::var("MYSQL_DB_PORT").expect("MYSQL_DB_PORT var not found");
@@ -377,11 +1924,7 @@ pub mod test_mysql_pagination {
         #[allow(dead_code)]
         pub struct States {
             id: i64,
-            country_name: String,
             name: String,
-            extension: i32,
-            created_at: OffsetDateTime,
-            updated_at: Option<OffsetDateTime>,
         }
 
         let pool: MySqlPool = match MySqlPoolOptions::new()
@@ -398,24 +1941,80 @@ pub mod test_mysql_pagination {
             }
         };
 
-        let query: QueryBuilder<MySql> = QueryBuilder::<MySql>::new("SELECT * FROM states");
+        let query: QueryBuilder<MySql> = QueryBuilder::<MySql>::new("SELECT id, name FROM states");
 
-        let users_pagination: PaginationResult<Page<States>> = query.paginate(&pool, 4, 7).await;
-        assert!(users_pagination.is_err());
+        let (book, stopped_early): (Book<States>, bool) = query
+            .paginate_bounded(&pool, 7, Duration::ZERO)
+            .await
+            .unwrap();
+        assert!(stopped_early);
+        assert_eq!(book.get_sheets().len(), 1);
+        assert!(book.get_sheets()[0].verify_fields().is_ok());
+        assert_eq!(book.get_sheets()[0].get_total(), 100);
+
+        let (book, stopped_early): (Book<States>, bool) = query
+            .paginate_bounded(&pool, 7, Duration::from_secs(60))
+            .await
+            .unwrap();
+        assert!(!stopped_early);
+        assert_eq!(book.get_sheets().len(), 15);
+        assert!(book.verify().is_ok());
+    }
 
-        let error: String = users_pagination.unwrap_err().to_string();
-        assert_eq!(
-            error,
-            "SQLX ERROR- no column found for name: extension".to_string(),
-        )
+    /// Test paginator memoizes the total and matches a direct pagination
+    #[tokio::test]
+    async fn test_paginator_memoized_count() {
+        use sqlx::mysql::MySqlPoolOptions;
+        use sqlx::{FromRow, MySql, MySqlPool, QueryBuilder};
+
+        let db_host: String = env::var("DB_HOST").expect("DB_HOST var not found");
+        let db_port: String = env::var("MYSQL_DB_PORT").expect("MYSQL_DB_PORT var not found");
+        let db_user: String = env::var("DB_USER").expect("DB_USER var not found");
+        let db_password: String = env::var("DB_PASSWORD").expect("DB_PASSWORD var not found");
+        let db_name: String = env::var("DB_NAME").expect("DB_NAME var not found");
+
+        #[derive(Clone, Debug, FromRow, PartialEq)]
+        pub struct States {
+            id: i64,
+            name: String,
+        }
+
+        let pool: MySqlPool = match MySqlPoolOptions::new()
+            .max_connections(1)
+            .connect(&format!(
+                "mysql://{}:{}@{}:{}/{}",
+                db_user, db_password, db_host, db_port, db_name
+            ))
+            .await
+        {
+            Ok(pool) => pool,
+            Err(e) => {
+                panic!("Failed to connect to MySQL: {:?}", e);
+            }
+        };
+
+        let query: QueryBuilder<MySql> =
+            QueryBuilder::<MySql>::new("SELECT id, name FROM states ORDER BY id");
+
+        let paginator: Paginator<MySql, States> = Paginator::new(&query, &pool).await.unwrap();
+        assert_eq!(paginator.get_total(), 100);
+
+        for page in [0, 4, 14] {
+            let fetched: Page<States> = paginator.fetch_page(&pool, page, 7).await.unwrap();
+            let paginated: Page<States> = query.paginate(&pool, page, 7).await.unwrap();
+            assert_eq!(fetched, paginated);
+            assert_eq!(paginator.get_total(), 100);
+        }
+
+        let error: PaginationError = paginator.fetch_page(&pool, 15, 7).await.unwrap_err();
+        assert!(error.get_error_kind().is_out_of_range_page());
     }
 
-    /// Test pagination with invalid page
+    /// Test pagination with bound arguments
     #[tokio::test]
-    async fn test_pagination_invalid_page() {
+    async fn test_pagination_with_bound_arguments() {
         use sqlx::mysql::MySqlPoolOptions;
         use sqlx::{FromRow, MySql, MySqlPool, QueryBuilder};
-        use time::OffsetDateTime;
 
         let db_host: String = env::var("DB_HOST").expect("DB_HOST var not found");
         let db_port: String = env::var("MYSQL_DB_PORT").expect("MYSQL_DB_PORT var not found");
@@ -423,14 +2022,11 @@ pub mod test_mysql_pagination {
         let db_password: String = env::var("DB_PASSWORD").expect("DB_PASSWORD var not found");
         let db_name: String = env::var("DB_NAME").expect("DB_NAME var not found");
 
-        #[derive(Clone, Debug, FromRow)]
+        #[derive(Clone, FromRow)]
         #[allow(dead_code)]
         pub struct States {
             id: i64,
-            country_name: String,
             name: String,
-            created_at: OffsetDateTime,
-            updated_at: Option<OffsetDateTime>,
         }
 
         let pool: MySqlPool = match MySqlPoolOptions::new()
@@ -447,15 +2043,166 @@ pub mod test_mysql_pagination {
             }
         };
 
-        let query: QueryBuilder<MySql> = QueryBuilder::<MySql>::new("SELECT * FROM states");
+        let mut query: QueryBuilder<MySql> =
+            QueryBuilder::<MySql>::new("SELECT id, name FROM states WHERE name LIKE ");
+        query.push_bind("State 1%").push(" ORDER BY id");
 
-        let users_pagination: PaginationResult<Page<States>> = query.paginate(&pool, 5, 30).await;
-        assert!(users_pagination.is_err());
+        let states: Page<States> = query.paginate_bound(&pool, 1, 5).await.unwrap();
+        assert_eq!(states.get_items().len(), 5);
+        assert_eq!(states.get_page(), 1);
+        assert_eq!(states.get_pages(), 3);
+        assert_eq!(states.get_total(), 12);
 
-        let error: String = users_pagination.unwrap_err().to_string();
-        assert_eq!(
-            error,
-            "FIELD VALUE ERROR- Page index '5' exceeds total pages '4'".to_string(),
-        )
+        assert_eq!(states.get_items()[0].name, "State 14");
+        assert_eq!(states.get_items()[4].name, "State 18");
+    }
+
+    /// Test pagination of all the records into a book
+    #[tokio::test]
+    async fn test_paginate_all() {
+        use sqlx::mysql::MySqlPoolOptions;
+        use sqlx::{FromRow, MySql, MySqlPool, QueryBuilder};
+
+        let db_host: String = env::var("DB_HOST").expect("DB_HOST var not found");
+        let db_port: String = env::var("MYSQL_DB_PORT").expect("MYSQL_DB_PORT var not found");
+        let db_user: String = env::var("DB_USER").expect("DB_USER var not found");
+        let db_password: String = env::var("DB_PASSWORD").expect("DB_PASSWORD var not found");
+        let db_name: String = env::var("DB_NAME").expect("DB_NAME var not found");
+
+        #[derive(Clone, Debug, FromRow, PartialEq)]
+        pub struct States {
+            id: i64,
+            name: String,
+        }
+
+        let pool: MySqlPool = match MySqlPoolOptions::new()
+            .max_connections(1)
+            .connect(&format!(
+                "mysql://{}:{}@{}:{}/{}",
+                db_user, db_password, db_host, db_port, db_name
+            ))
+            .await
+        {
+            Ok(pool) => pool,
+            Err(e) => {
+                panic!("Failed to connect to MySQL: {:?}", e);
+            }
+        };
+
+        let query: QueryBuilder<MySql> =
+            QueryBuilder::<MySql>::new("SELECT id, name FROM states ORDER BY id");
+
+        let book: Book<States> = query.paginate_all(&pool, 30).await.unwrap();
+        assert_eq!(book.get_sheets().len(), 4);
+        assert_eq!(book.get_sheets()[3].get_items().len(), 10);
+        assert!(book.verify().is_ok());
+
+        let states: Vec<States> =
+            sqlx::query_as::<_, States>("SELECT id, name FROM states ORDER BY id")
+                .fetch_all(&pool)
+                .await
+                .unwrap();
+        let items: Vec<States> = book
+            .into_iter()
+            .flat_map(|sheet| sheet.into_iter())
+            .collect();
+        assert_eq!(items, states);
+    }
+
+    /// Test pagination of all the records into a stream of pages
+    #[tokio::test]
+    async fn test_stream_pages() {
+        use futures_util::StreamExt;
+        use sqlx::mysql::MySqlPoolOptions;
+        use sqlx::{FromRow, MySql, MySqlPool, QueryBuilder};
+
+        let db_host: String = env::var("DB_HOST").expect("DB_HOST var not found");
+        let db_port: String = env::var("MYSQL_DB_PORT").expect("MYSQL_DB_PORT var not found");
+        let db_user: String = env::var("DB_USER").expect("DB_USER var not found");
+        let db_password: String = env::var("DB_PASSWORD").expect("DB_PASSWORD var not found");
+        let db_name: String = env::var("DB_NAME").expect("DB_NAME var not found");
+
+        #[derive(Clone, Debug, FromRow, PartialEq)]
+        pub struct States {
+            id: i64,
+            name: String,
+        }
+
+        let pool: MySqlPool = match MySqlPoolOptions::new()
+            .max_connections(1)
+            .connect(&format!(
+                "mysql://{}:{}@{}:{}/{}",
+                db_user, db_password, db_host, db_port, db_name
+            ))
+            .await
+        {
+            Ok(pool) => pool,
+            Err(e) => {
+                panic!("Failed to connect to MySQL: {:?}", e);
+            }
+        };
+
+        let query: QueryBuilder<MySql> =
+            QueryBuilder::<MySql>::new("SELECT id, name FROM states ORDER BY id");
+
+        let sheets: Vec<Page<States>> = query
+            .stream_pages(&pool, 40)
+            .map(|sheet| sheet.unwrap())
+            .collect()
+            .await;
+        assert_eq!(sheets.len(), 3);
+        assert_eq!(sheets[0].get_items()[0].id, 1);
+        assert_eq!(sheets[2].get_items().len(), 20);
+        assert_eq!(sheets[2].get_next_page(), None);
+    }
+
+    /// Test pagination of the records into a stream of pages stopped early by a time budget
+    #[tokio::test]
+    async fn test_stream_pages_bounded() {
+        use futures_util::StreamExt;
+        use sqlx::mysql::MySqlPoolOptions;
+        use sqlx::{FromRow, MySql, MySqlPool, QueryBuilder};
+        use std::time::Duration;
+
+        let db_host: String = env::var("DB_HOST").expect("DB_HOST var not found");
+        let db_port: String = env::var("MYSQL_DB_PORT").expect("MYSQL_DB_PORT var not found");
+        let db_user: String = env::var("DB_USER").expect("DB_USER var not found");
+        let db_password: String = env::var("DB_PASSWORD").expect("DB_PASSWORD var not found");
+        let db_name: String = env::var("DB_NAME").expect("DB_NAME var not found");
+
+        #[derive(Clone, Debug, FromRow, PartialEq)]
+        pub struct States {
+            id: i64,
+            name: String,
+        }
+
+        let pool: MySqlPool = match MySqlPoolOptions::new()
+            .max_connections(1)
+            .connect(&format!(
+                "mysql://{}:{}@{}:{}/{}",
+                db_user, db_password, db_host, db_port, db_name
+            ))
+            .await
+        {
+            Ok(pool) => pool,
+            Err(e) => {
+                panic!("Failed to connect to MySQL: {:?}", e);
+            }
+        };
+
+        let query: QueryBuilder<MySql> =
+            QueryBuilder::<MySql>::new("SELECT id, name FROM states ORDER BY id");
+
+        let sheets: Vec<(Page<States>, bool)> = query
+            .stream_pages_bounded(&pool, 40, Duration::ZERO)
+            .map(|sheet| sheet.unwrap())
+            .collect()
+            .await;
+        assert_eq!(sheets.len(), 1);
+        let (sheet, stopped_early): &(Page<States>, bool) = &sheets[0];
+        assert!(stopped_early);
+        assert!(sheet.verify_fields().is_ok());
+        assert_eq!(sheet.get_items()[0].id, 1);
+        assert_eq!(sheet.get_next_page(), Some(1));
     }
 }
@@ -0,0 +1,57 @@
+// Test stream pagination functions.
+#[cfg(feature = "stream")]
+#[cfg(test)]
+mod test_stream_pagination {
+    use futures_util::stream;
+    use page_hunter::*;
+
+    /// Test paginate_stream with a middle page.
+    #[tokio::test]
+    async fn test_paginate_stream() {
+        let records: Vec<u32> = (1..=5).collect();
+
+        let page: Page<u32> = paginate_stream(stream::iter(records.clone()), 1, 2)
+            .await
+            .unwrap();
+        assert_eq!(page.get_items(), &vec![3, 4]);
+        assert_eq!(page.get_page(), 1);
+        assert_eq!(page.get_size(), 2);
+        assert_eq!(page.get_total(), 5);
+        assert_eq!(page.get_pages(), 3);
+        assert_eq!(page.get_previous_page(), Some(0));
+        assert_eq!(page.get_next_page(), Some(2));
+        assert_eq!(page, paginate_records(&records, 1, 2).unwrap());
+    }
+
+    /// Test paginate_stream with the last page and an empty stream.
+    #[tokio::test]
+    async fn test_paginate_stream_last_and_empty() {
+        let page: Page<u32> = paginate_stream(stream::iter(1..=5), 2, 2).await.unwrap();
+        assert_eq!(page.get_items(), &vec![5]);
+        assert_eq!(page.get_next_page(), None);
+
+        let empty: Page<u32> = paginate_stream(stream::iter(Vec::<u32>::new()), 0, 2)
+            .await
+            .unwrap();
+        assert!(empty.get_items().is_empty());
+        assert_eq!(empty.get_total(), 0);
+        assert_eq!(empty.get_pages(), 1);
+    }
+
+    /// Test paginate_stream with a page out of range.
+    #[tokio::test]
+    async fn test_paginate_stream_page_out_of_range() {
+        let error: PaginationError = paginate_stream(stream::iter(1..=5), 3, 2)
+            .await
+            .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "OUT OF RANGE PAGE ERROR- Page index '3' exceeds total pages '3'"
+        );
+
+        let error: PaginationError = paginate_stream(stream::iter(vec![1, 2, 3]), usize::MAX, 2)
+            .await
+            .unwrap_err();
+        assert!(error.get_error_kind().is_out_of_range_page());
+    }
+}
@@ -0,0 +1,73 @@
+/// Test utoipa 5.x features implementation.
+#[cfg(feature = "utoipa-5")]
+#[cfg(test)]
+pub mod test_utoipa_5_features {
+    use page_hunter::{Book, Page};
+    use serde_json::to_string;
+    use utoipa5::openapi::schema::{ObjectBuilder, Schema, Type};
+    use utoipa5::openapi::RefOr;
+    use utoipa5::{PartialSchema, ToSchema};
+
+    /// Rename the page links of an expected JSON string as serialized with the `serde-camel-case` feature.
+    fn with_link_names(json: &str) -> String {
+        match cfg!(feature = "serde-camel-case") {
+            true => json
+                .replace("\"previous_page\":", "\"previousPage\":")
+                .replace("\"next_page\":", "\"nextPage\":"),
+            false => json.to_string(),
+        }
+    }
+
+    #[allow(dead_code)]
+    struct Record {
+        number: u8,
+    }
+
+    impl PartialSchema for Record {
+        fn schema() -> RefOr<Schema> {
+            ObjectBuilder::new()
+                .property("number", ObjectBuilder::new().schema_type(Type::Integer))
+                .required("number")
+                .into()
+        }
+    }
+
+    impl ToSchema for Record {
+        fn name() -> std::borrow::Cow<'static, str> {
+            std::borrow::Cow::Borrowed("Record")
+        }
+    }
+
+    #[test]
+    fn test_page_to_schema() {
+        assert_eq!(<Page<Record> as ToSchema>::name(), "Page");
+
+        let json_string: String = match to_string(&<Page<Record> as PartialSchema>::schema()) {
+            Ok(json_string) => json_string,
+            Err(e) => panic!("Error serializing schema: {}", e),
+        };
+        assert_eq!(
+            json_string,
+            with_link_names("{\"type\":\"object\",\"description\":\"Model to represent paginated items.\",\"required\":[\"items\",\"page\",\"size\",\"total\",\"pages\"],\"properties\":{\"items\":{\"type\":\"array\",\"items\":{\"type\":\"object\",\"required\":[\"number\"],\"properties\":{\"number\":{\"type\":\"integer\"}}}},\"next_page\":{\"type\":[\"integer\",\"null\"],\"format\":\"int64\",\"description\":\"Represents the next page index in a Page. If there is no next page, it will be None.\",\"examples\":[2]},\"page\":{\"type\":\"integer\",\"format\":\"int64\",\"description\":\"The page index in a Page. It starts from 0 to pages - 1.\",\"examples\":[1],\"minimum\":0},\"pages\":{\"type\":\"integer\",\"format\":\"int64\",\"description\":\"Represents the total number of pages required for paginate the items.\",\"examples\":[3],\"minimum\":1},\"previous_page\":{\"type\":[\"integer\",\"null\"],\"format\":\"int64\",\"description\":\"Represents the previous page index in a Page. If there is no previous page, it will be None.\",\"examples\":[0]},\"size\":{\"type\":\"integer\",\"format\":\"int64\",\"description\":\"The maximum number of elements per Page. items length must be equal to size value for all pages except the last page, when items length could be less than or equal to size.\",\"examples\":[10],\"minimum\":0},\"total\":{\"type\":\"integer\",\"format\":\"int64\",\"description\":\"The total number of records used for pagination.\",\"examples\":[25],\"minimum\":0}}}")
+        );
+
+        let mut schemas: Vec<(String, RefOr<Schema>)> = Vec::new();
+        <Page<Record> as ToSchema>::schemas(&mut schemas);
+        assert_eq!(schemas.len(), 1);
+        assert_eq!(schemas[0].0, "Record");
+    }
+
+    #[test]
+    fn test_book_to_schema() {
+        assert_eq!(<Book<Record> as ToSchema>::name(), "Book");
+
+        let json_string: String = match to_string(&<Book<Record> as PartialSchema>::schema()) {
+            Ok(json_string) => json_string,
+            Err(e) => panic!("Error serializing schema: {}", e),
+        };
+        assert_eq!(
+            json_string,
+            with_link_names("{\"type\":\"object\",\"description\":\"Model to represent a book of paginated items.\",\"required\":[\"sheets\"],\"properties\":{\"sheets\":{\"type\":\"array\",\"items\":{\"type\":\"object\",\"description\":\"Model to represent paginated items.\",\"required\":[\"items\",\"page\",\"size\",\"total\",\"pages\"],\"properties\":{\"items\":{\"type\":\"array\",\"items\":{\"type\":\"object\",\"required\":[\"number\"],\"properties\":{\"number\":{\"type\":\"integer\"}}}},\"next_page\":{\"type\":[\"integer\",\"null\"],\"format\":\"int64\",\"description\":\"Represents the next page index in a Page. If there is no next page, it will be None.\",\"examples\":[2]},\"page\":{\"type\":\"integer\",\"format\":\"int64\",\"description\":\"The page index in a Page. It starts from 0 to pages - 1.\",\"examples\":[1],\"minimum\":0},\"pages\":{\"type\":\"integer\",\"format\":\"int64\",\"description\":\"Represents the total number of pages required for paginate the items.\",\"examples\":[3],\"minimum\":1},\"previous_page\":{\"type\":[\"integer\",\"null\"],\"format\":\"int64\",\"description\":\"Represents the previous page index in a Page. If there is no previous page, it will be None.\",\"examples\":[0]},\"size\":{\"type\":\"integer\",\"format\":\"int64\",\"description\":\"The maximum number of elements per Page. items length must be equal to size value for all pages except the last page, when items length could be less than or equal to size.\",\"examples\":[10],\"minimum\":0},\"total\":{\"type\":\"integer\",\"format\":\"int64\",\"description\":\"The total number of records used for pagination.\",\"examples\":[25],\"minimum\":0}}},\"description\":\"Represents a paginated items as a collection of pages\"}}}")
+        );
+    }
+}
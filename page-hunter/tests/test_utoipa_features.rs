@@ -6,6 +6,26 @@ pub mod test_utoipa_features {
     use serde_json::to_string;
     use utoipa::ToSchema;
 
+    /// Rename the page links of an expected JSON string as serialized with the `serde-camel-case` feature.
+    fn with_link_names(json: &str) -> String {
+        match cfg!(feature = "serde-camel-case") {
+            true => json
+                .replace("\"previous_page\":", "\"previousPage\":")
+                .replace("\"next_page\":", "\"nextPage\":"),
+            false => json.to_string(),
+        }
+    }
+
+    /// Drop the null page links of an expected JSON string as serialized with the `serde-skip-null-links` feature.
+    fn without_null_links(json: &str) -> String {
+        match cfg!(feature = "serde-skip-null-links") {
+            true => json
+                .replace(",\"previous_page\":null", "")
+                .replace(",\"next_page\":null", ""),
+            false => json.to_string(),
+        }
+    }
+
     #[test]
     fn test_book_to_schema() {
         #[derive(Clone, ToSchema)]
@@ -23,7 +43,47 @@ pub mod test_utoipa_features {
         };
         assert_eq!(
             json_string,
-            "{\"type\":\"object\",\"description\":\"Model to represent a book of paginated items.\",\"required\":[\"sheets\"],\"properties\":{\"sheets\":{\"type\":\"array\",\"items\":{\"type\":\"object\",\"description\":\"Model to represent paginated items.\",\"required\":[\"items\",\"page\",\"size\",\"total\",\"pages\"],\"properties\":{\"items\":{\"type\":\"object\",\"required\":[\"number\"],\"properties\":{\"number\":{\"type\":\"integer\",\"format\":\"int32\",\"minimum\":0}}},\"page\":{\"type\":\"integer\",\"format\":\"int64\",\"description\":\"The page index in a Page. It starts from 0 to pages - 1.\",\"minimum\":0},\"pages\":{\"type\":\"integer\",\"format\":\"int64\",\"description\":\"Represents the total number of pages required for paginate the items.\",\"minimum\":1},\"previous_page\":{\"type\":\"integer\",\"format\":\"int64\",\"description\":\"Represents the previous page index in a Page. If there is no previous page, it will be None.\",\"properties\":{\"next_page\":{\"type\":\"integer\",\"format\":\"int64\",\"description\":\"Represents the next page index in a Page. If there is no next page, it will be None.\"}}},\"size\":{\"type\":\"integer\",\"format\":\"int64\",\"description\":\"The maximum number of elements per Page. items length must be equal to size value for all pages except the last page, when items length could be less than or equal to size.\",\"minimum\":0},\"total\":{\"type\":\"integer\",\"format\":\"int64\",\"description\":\"The total number of records used for pagination.\",\"minimum\":0}}},\"description\":\"Represents a paginated items as a collection of pages\"}}}"
+            with_link_names("{\"type\":\"object\",\"description\":\"Model to represent a book of paginated items.\",\"required\":[\"sheets\"],\"properties\":{\"sheets\":{\"type\":\"array\",\"items\":{\"type\":\"object\",\"description\":\"Model to represent paginated items.\",\"required\":[\"items\",\"page\",\"size\",\"total\",\"pages\"],\"properties\":{\"items\":{\"type\":\"array\",\"items\":{\"type\":\"object\",\"required\":[\"number\"],\"properties\":{\"number\":{\"type\":\"integer\",\"format\":\"int32\",\"minimum\":0}}}},\"next_page\":{\"type\":\"integer\",\"format\":\"int64\",\"description\":\"Represents the next page index in a Page. If there is no next page, it will be None.\",\"example\":2,\"nullable\":true},\"page\":{\"type\":\"integer\",\"format\":\"int64\",\"description\":\"The page index in a Page. It starts from 0 to pages - 1.\",\"example\":1,\"minimum\":0},\"pages\":{\"type\":\"integer\",\"format\":\"int64\",\"description\":\"Represents the total number of pages required for paginate the items.\",\"example\":3,\"minimum\":1},\"previous_page\":{\"type\":\"integer\",\"format\":\"int64\",\"description\":\"Represents the previous page index in a Page. If there is no previous page, it will be None.\",\"example\":0,\"nullable\":true},\"size\":{\"type\":\"integer\",\"format\":\"int64\",\"description\":\"The maximum number of elements per Page. items length must be equal to size value for all pages except the last page, when items length could be less than or equal to size.\",\"example\":10,\"minimum\":0},\"total\":{\"type\":\"integer\",\"format\":\"int64\",\"description\":\"The total number of records used for pagination.\",\"example\":25,\"minimum\":0}}},\"description\":\"Represents a paginated items as a collection of pages\"}}}")
+        );
+    }
+
+    #[test]
+    fn test_page_to_schema() {
+        use page_hunter::Page;
+
+        #[derive(Clone, ToSchema)]
+        #[allow(dead_code)]
+        struct Record {
+            number: u8,
+        }
+
+        let (schema_name, schema_object) = Page::<Record>::schema();
+        assert_eq!(schema_name, "Page");
+
+        let json_string: String = match to_string(&schema_object) {
+            Ok(json_string) => json_string,
+            Err(e) => panic!("Error serializing schema: {}", e),
+        };
+        assert_eq!(
+            json_string,
+            with_link_names("{\"type\":\"object\",\"description\":\"Model to represent paginated items.\",\"required\":[\"items\",\"page\",\"size\",\"total\",\"pages\"],\"properties\":{\"items\":{\"type\":\"array\",\"items\":{\"type\":\"object\",\"required\":[\"number\"],\"properties\":{\"number\":{\"type\":\"integer\",\"format\":\"int32\",\"minimum\":0}}}},\"next_page\":{\"type\":\"integer\",\"format\":\"int64\",\"description\":\"Represents the next page index in a Page. If there is no next page, it will be None.\",\"example\":2,\"nullable\":true},\"page\":{\"type\":\"integer\",\"format\":\"int64\",\"description\":\"The page index in a Page. It starts from 0 to pages - 1.\",\"example\":1,\"minimum\":0},\"pages\":{\"type\":\"integer\",\"format\":\"int64\",\"description\":\"Represents the total number of pages required for paginate the items.\",\"example\":3,\"minimum\":1},\"previous_page\":{\"type\":\"integer\",\"format\":\"int64\",\"description\":\"Represents the previous page index in a Page. If there is no previous page, it will be None.\",\"example\":0,\"nullable\":true},\"size\":{\"type\":\"integer\",\"format\":\"int64\",\"description\":\"The maximum number of elements per Page. items length must be equal to size value for all pages except the last page, when items length could be less than or equal to size.\",\"example\":10,\"minimum\":0},\"total\":{\"type\":\"integer\",\"format\":\"int64\",\"description\":\"The total number of records used for pagination.\",\"example\":25,\"minimum\":0}}}")
+        );
+    }
+
+    /// Test that the `utoipa` feature enables the `serde` feature transitively.
+    #[test]
+    fn test_utoipa_enables_serde() {
+        use page_hunter::Page;
+
+        let page: Page<u8> = Page::new(&vec![1, 2], 0, 2, 3).unwrap();
+
+        let json_string: String = match to_string(&page) {
+            Ok(json_string) => json_string,
+            Err(e) => panic!("Error serializing page: {}", e),
+        };
+        assert_eq!(
+            json_string,
+with_link_names(&without_null_links("{\"items\":[1,2],\"page\":0,\"size\":2,\"total\":3,\"pages\":2,\"previous_page\":null,\"next_page\":1}"))
         );
     }
 }
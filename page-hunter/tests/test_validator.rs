@@ -0,0 +1,62 @@
+// Test validator integration.
+#[cfg(feature = "validator")]
+#[cfg(test)]
+mod test_validator {
+    use page_hunter::*;
+    use validator::Validate;
+
+    #[derive(Validate)]
+    struct SearchResponse {
+        #[validate(length(min = 1))]
+        query: String,
+
+        #[validate(nested)]
+        results: Page<u32>,
+    }
+
+    /// Test [`Page`] validate method for a valid page.
+    #[test]
+    fn test_page_validate() {
+        let page: Page<u32> = Page::new(&vec![1, 2], 0, 2, 5).unwrap();
+        assert!(page.validate().is_ok());
+    }
+
+    /// Test [`Page`] validation nested in a struct deriving [`Validate`].
+    #[test]
+    fn test_page_nested_validate() {
+        let response: SearchResponse = SearchResponse {
+            query: String::from("books"),
+            results: Page::new(&vec![3, 4], 1, 2, 5).unwrap(),
+        };
+        assert!(response.validate().is_ok());
+
+        let response: SearchResponse = SearchResponse {
+            query: String::new(),
+            results: Page::default(),
+        };
+        let errors: validator::ValidationErrors = response.validate().unwrap_err();
+        assert!(errors.field_errors().contains_key("query"));
+        assert!(!errors.errors().contains_key("results"));
+    }
+
+    /// Test [`Page`] validation error for a page with broken invariants.
+    #[test]
+    fn test_page_validate_error() {
+        let mut page: Page<u32> = Page::new(&vec![1, 2], 0, 2, 5).unwrap();
+        page.get_items_mut().pop();
+
+        let errors: validator::ValidationErrors = page.validate().unwrap_err();
+        let page_errors: &Vec<validator::ValidationError> = errors.field_errors()["__all__"];
+        assert_eq!(page_errors[0].code, "page");
+        assert_eq!(
+            page_errors[0].message.as_deref(),
+            Some("FIELD VALUE ERROR- Items length '1' is not equal to page size '2' for an intermediate page '0'")
+        );
+
+        let response: SearchResponse = SearchResponse {
+            query: String::from("books"),
+            results: page,
+        };
+        assert!(response.validate().is_err());
+    }
+}